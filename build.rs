@@ -0,0 +1,65 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// Generates a test for each answer given in `examples/<year>/dayNN.toml`,
+// so that adding an example is a data change only.
+fn main() {
+    let dir = Path::new("examples");
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let mut tests = String::new();
+    let mut years: Vec<_> = fs::read_dir(dir)
+        .map(|entries| entries.flatten().map(|entry| entry.path()).collect())
+        .unwrap_or_default();
+    years.sort();
+    for year_dir in years {
+        let Some(year) = year_dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.parse::<u16>().ok())
+        else {
+            continue;
+        };
+        let mut days: Vec<_> = fs::read_dir(&year_dir)
+            .unwrap()
+            .flatten()
+            .map(|entry| entry.path())
+            .collect();
+        days.sort();
+        for path in days {
+            let Some(day) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_prefix("day")?.strip_suffix(".toml"))
+                .and_then(|day| day.parse::<u8>().ok())
+            else {
+                continue;
+            };
+            let content = fs::read_to_string(&path).unwrap();
+            let table: toml::Table = toml::from_str(&content)
+                .unwrap_or_else(|error| panic!("{}: {}", path.display(), error));
+            let examples = match table.get("example") {
+                Some(toml::Value::Array(examples)) => examples.clone(),
+                _ => Vec::new(),
+            };
+            for (i, example) in examples.iter().enumerate() {
+                for part in [1, 2] {
+                    if example.get(format!("part{}", part)).is_none() {
+                        continue;
+                    }
+                    tests.push_str(&format!(
+                        "#[test]\nfn y{year}_day{day:02}_example{n}_part{part}() {{\n    check({year}, {day}, {n}, {part});\n}}\n\n",
+                        year = year,
+                        day = day,
+                        n = i + 1,
+                        part = part,
+                    ));
+                }
+            }
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(out, tests).unwrap();
+}
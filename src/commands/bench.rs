@@ -0,0 +1,293 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use colored::Colorize;
+
+use aoc::error::Error;
+use aoc::utils::bench::{self, Baseline, Comparison, Measure, Stats};
+use aoc::utils::examples;
+use aoc::utils::format::Format;
+use aoc::utils::input::Inputs;
+use aoc::utils::preflight;
+use aoc::years;
+
+use crate::selection::DaySelection;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// The requested days to benchmark: `6`, `6:2`, `1..=11`, `all` or `last`
+    days: Vec<DaySelection>,
+
+    /// Number of timed runs of each part
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Number of untimed runs of each part beforehand
+    #[arg(short, long, default_value_t = 2)]
+    warmup: u32,
+
+    /// Run on the n-th example of each day instead of the puzzle input
+    #[arg(short, long, value_name = "N", num_args = 0..=1, default_missing_value = "1")]
+    example: Option<u8>,
+
+    /// Directory holding the examples
+    #[arg(long, value_name = "DIR", default_value = examples::DEFAULT_DIR)]
+    examples: PathBuf,
+
+    /// How to print the results
+    #[arg(short, long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// Directory holding the baselines, kept apart for each example
+    #[arg(long, value_name = "DIR", default_value = bench::DEFAULT_DIR)]
+    baseline: PathBuf,
+
+    /// Save the median timings as the new baseline, accepting any regression
+    #[arg(short, long)]
+    save: bool,
+
+    /// Allowed slowdown over the baseline, in percent
+    #[arg(short, long, value_name = "PCT", default_value_t = 10.0)]
+    tolerance: f64,
+}
+
+/// Timings of a day part, ready to be printed
+struct Record {
+    year: u16,
+    day: u8,
+    part: u8,
+    measure: Measure,
+    comparison: Comparison,
+}
+
+static CSV_HEADER: &str = "year,day,part,runs,\
+    parse_min,parse_median,parse_mean,parse_stddev,\
+    solve_min,solve_median,solve_mean,solve_stddev,\
+    total_min,total_median,total_mean,total_stddev,\
+    baseline,regressed";
+
+fn baseline_of(comparison: Comparison) -> Option<f64> {
+    match comparison {
+        Comparison::Within { baseline } | Comparison::Regressed { baseline } => Some(baseline),
+        Comparison::Unknown => None,
+    }
+}
+
+fn print_table(record: &Record, args: &Args) {
+    let stats = |name: &str, stats: &Stats| {
+        println!(
+            " * {}: min {:.6}s, median {:.6}s, mean {:.6}s, stddev {:.6}s",
+            name, stats.min, stats.median, stats.mean, stats.stddev
+        );
+    };
+
+    println!(
+        "# Benchmarking {} day #{} part {} ({} runs, {} warm-up)...",
+        record.year, record.day, record.part, args.runs, args.warmup
+    );
+    println!(" * Solution found: {}", record.measure.answer);
+    stats("Parse", &record.measure.parse);
+    stats("Solve", &record.measure.solve);
+    stats("Total", &record.measure.total);
+
+    let median = record.measure.total.median;
+    match record.comparison {
+        Comparison::Within { baseline } => println!(
+            " * Baseline: {:.6}s ({:+.1}%)",
+            baseline,
+            (median / baseline - 1.0) * 100.0
+        ),
+        Comparison::Regressed { baseline } => println!(
+            " * Baseline: {:.6}s ({:+.1}%) {}",
+            baseline,
+            (median / baseline - 1.0) * 100.0,
+            "REGRESSION".red().bold()
+        ),
+        Comparison::Unknown => println!(" * Baseline: {}", "none".yellow()),
+    }
+}
+
+fn stats_json(stats: &Stats) -> String {
+    format!(
+        "{{\"min\":{},\"median\":{},\"mean\":{},\"stddev\":{}}}",
+        stats.min, stats.median, stats.mean, stats.stddev
+    )
+}
+
+fn print_json(records: &[Record], args: &Args) {
+    let records: Vec<String> = records
+        .iter()
+        .map(|record| {
+            let baseline = match baseline_of(record.comparison) {
+                Some(baseline) => baseline.to_string(),
+                None => "null".to_string(),
+            };
+            format!(
+                "{{\"year\":{},\"day\":{},\"part\":{},\"runs\":{},\"parse\":{},\"solve\":{},\"total\":{},\"baseline\":{},\"regressed\":{}}}",
+                record.year,
+                record.day,
+                record.part,
+                args.runs,
+                stats_json(&record.measure.parse),
+                stats_json(&record.measure.solve),
+                stats_json(&record.measure.total),
+                baseline,
+                matches!(record.comparison, Comparison::Regressed { .. })
+            )
+        })
+        .collect();
+    println!("[{}]", records.join(","));
+}
+
+fn print_csv(record: &Record, args: &Args) {
+    let stats = |stats: &Stats| {
+        format!(
+            "{},{},{},{}",
+            stats.min, stats.median, stats.mean, stats.stddev
+        )
+    };
+    let baseline = baseline_of(record.comparison)
+        .map(|baseline| baseline.to_string())
+        .unwrap_or_default();
+    println!(
+        "{},{},{},{},{},{},{},{},{}",
+        record.year,
+        record.day,
+        record.part,
+        args.runs,
+        stats(&record.measure.parse),
+        stats(&record.measure.solve),
+        stats(&record.measure.total),
+        baseline,
+        matches!(record.comparison, Comparison::Regressed { .. })
+    );
+}
+
+pub fn execute(args: Args, inputs: &Inputs, year: u16) -> ExitCode {
+    let mut status = ExitCode::SUCCESS;
+
+    // Baselines, loaded once per year
+    let mut baselines: HashMap<u16, Baseline> = HashMap::new();
+    let mut records: Vec<Record> = Vec::new();
+
+    if args.format == Format::Csv {
+        println!("{}", CSV_HEADER);
+    }
+
+    for selection in &args.days {
+        let year = selection.year(year);
+        for day in selection.days(&years::registered(year)) {
+            // Retrieve the request day
+            let solver = match years::find(year, day) {
+                Ok(solver) => solver,
+                Err(error) => {
+                    eprintln!("# Error: {}", error);
+                    status = ExitCode::FAILURE;
+                    continue;
+                }
+            };
+
+            // Retrieve the input
+            let (loaded, source) = match args.example {
+                Some(n) => (
+                    examples::get(&args.examples, year, day, n).map(|example| example.input),
+                    format!(
+                        "{} (example {})",
+                        examples::path(&args.examples, year, day).display(),
+                        n
+                    ),
+                ),
+                None => (
+                    inputs.load(year, day),
+                    inputs.path(year, day).display().to_string(),
+                ),
+            };
+            // Normalized once here, so that the diagnostics quote the lines as parsed
+            let input = match loaded {
+                Ok(input) => preflight::normalize(&input),
+                Err(error) => {
+                    eprintln!("# Error: {}", error);
+                    status = ExitCode::FAILURE;
+                    continue;
+                }
+            };
+
+            let baseline = match baselines.entry(year) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => match Baseline::load(&args.baseline, year, args.example) {
+                    Ok(baseline) => entry.insert(baseline),
+                    Err(error) => {
+                        eprintln!("# Error: {}", error);
+                        return ExitCode::FAILURE;
+                    }
+                },
+            };
+
+            for part in selection.parts() {
+                let runs = args.runs as usize;
+                let measure = match bench::measure(solver, part, &input, args.warmup as usize, runs)
+                {
+                    Ok(measure) => measure,
+                    Err(error) => {
+                        match error.locate(&source, &input) {
+                            Error::Input(diagnostic) => eprint!("{}", diagnostic.render()),
+                            error => {
+                                eprintln!("# Error: {} day #{} part {}: {}", year, day, part, error)
+                            }
+                        }
+                        status = ExitCode::FAILURE;
+                        continue;
+                    }
+                };
+
+                let median = measure.total.median;
+                let comparison = baseline.compare(day, part, median, args.tolerance);
+                if let Comparison::Regressed { baseline } = comparison {
+                    if args.format != Format::Table {
+                        eprintln!(
+                            "# Regression: {} day #{} part {} took {:.6}s, baseline {:.6}s",
+                            year, day, part, median, baseline
+                        );
+                    }
+                    // Saving accepts the new timings as the reference
+                    if !args.save {
+                        status = ExitCode::FAILURE;
+                    }
+                }
+                if args.save {
+                    baseline.record(day, part, median);
+                }
+
+                let record = Record {
+                    year,
+                    day,
+                    part,
+                    measure,
+                    comparison,
+                };
+                match args.format {
+                    Format::Table => print_table(&record, &args),
+                    Format::Csv => print_csv(&record, &args),
+                    Format::Json => records.push(record),
+                }
+            }
+        }
+    }
+
+    if args.format == Format::Json {
+        print_json(&records, &args);
+    }
+
+    if args.save {
+        for baseline in baselines.values() {
+            if let Err(error) = baseline.save() {
+                eprintln!("# Error: {}", error);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    status
+}
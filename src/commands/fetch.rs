@@ -0,0 +1,74 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc::utils::cache::{Cache, Status, FETCH_INTERVAL};
+use aoc::utils::fetch::{self, DirFetcher, Fetcher, HttpFetcher, SESSION_ENV};
+use aoc::utils::input::Inputs;
+use aoc::years;
+
+use crate::selection::{DaySelection, Days};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// The days to fetch, every registered day of the year if omitted
+    days: Vec<DaySelection>,
+
+    /// Session cookie of the website, defaults to `$AOC_SESSION`
+    #[arg(long)]
+    session: Option<String>,
+
+    /// Copy the inputs from this directory instead of downloading them
+    #[arg(long, value_name = "DIR")]
+    from: Option<PathBuf>,
+}
+
+pub fn execute(args: Args, inputs: &Inputs, year: u16) -> ExitCode {
+    let mut status = ExitCode::SUCCESS;
+
+    let fetcher: Box<dyn Fetcher> = match (args.from, fetch::session(args.session)) {
+        (Some(dir), _) => Box::new(DirFetcher::new(dir)),
+        (None, Some(session)) => Box::new(HttpFetcher::new(session)),
+        (None, None) => {
+            eprintln!(
+                "# Error: no session cookie, use `--session` or `${}`",
+                SESSION_ENV
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut cache = Cache::new(inputs, fetcher, FETCH_INTERVAL);
+
+    let selections = match args.days.is_empty() {
+        true => vec![DaySelection {
+            year: None,
+            days: Days::All,
+            part: None,
+        }],
+        false => args.days,
+    };
+    for selection in selections {
+        // A single day is fetched even if not solved yet
+        let year = selection.year(year);
+        for day in selection.days(&years::registered(year)) {
+            match cache.get(year, day) {
+                Ok((path, Status::Cached)) => {
+                    println!(
+                        "# {} day #{} already cached in {}",
+                        year,
+                        day,
+                        path.display()
+                    )
+                }
+                Ok((path, Status::Fetched)) => {
+                    println!("# {} day #{} fetched into {}", year, day, path.display())
+                }
+                Err(error) => {
+                    eprintln!("# Error: {} day #{}: {}", year, day, error);
+                    status = ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+
+    status
+}
@@ -0,0 +1,5 @@
+pub mod bench;
+pub mod fetch;
+pub mod new;
+pub mod run;
+pub mod submit;
@@ -0,0 +1,54 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc::utils::examples;
+use aoc::utils::input::Inputs;
+use aoc::utils::scaffold::{self, YEARS_DIR};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// The day to add
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Title of the puzzle
+    #[arg(short, long, default_value = "")]
+    title: String,
+
+    /// Directory of the year modules, relative to the current one
+    #[arg(long, value_name = "DIR", default_value = YEARS_DIR)]
+    src: PathBuf,
+
+    /// Directory holding the examples
+    #[arg(long, value_name = "DIR", default_value = examples::DEFAULT_DIR)]
+    examples: PathBuf,
+}
+
+pub fn execute(args: Args, inputs: &Inputs, year: u16) -> ExitCode {
+    match scaffold::create(
+        &args.src,
+        inputs,
+        &args.examples,
+        year,
+        args.day,
+        &args.title,
+    ) {
+        Ok(created) => {
+            println!(
+                "# {} day #{} created in {}",
+                year,
+                args.day,
+                created.module.display()
+            );
+            for path in created.files {
+                println!(" * Created {}", path.display());
+            }
+            println!(" * Rebuild to run it");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("# Error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,474 @@
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use colored::Colorize;
+
+use aoc::answer::Answer;
+use aoc::error::{Error, Result};
+use aoc::solution::Solver;
+use aoc::utils::answers::{self, Answers, Verdict};
+use aoc::utils::checked;
+use aoc::utils::examples::{self, Example};
+use aoc::utils::format::{self, csv_field, json_string, Format};
+use aoc::utils::input::{self, Inputs};
+use aoc::utils::preflight;
+use aoc::utils::render::{self, Export};
+use aoc::utils::runner;
+use aoc::years;
+
+use crate::selection::DaySelection;
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// The requested days to run: `6`, `6:2`, `1..=11`, `all` or `last`
+    days: Vec<DaySelection>,
+
+    /// List the available days
+    #[arg(short, long)]
+    list: bool,
+
+    /// Run on the n-th example of each day instead of the puzzle input, checking its answers
+    #[arg(short, long, value_name = "N", num_args = 0..=1, default_missing_value = "1")]
+    example: Option<u8>,
+
+    /// Directory holding the examples
+    #[arg(long, value_name = "DIR", default_value = examples::DEFAULT_DIR)]
+    examples: PathBuf,
+
+    /// Read every requested day from this file instead, `-` for the standard input
+    #[arg(short, long, value_name = "FILE")]
+    input: Option<PathBuf>,
+
+    /// Compare each answer with the known one
+    #[arg(short, long, conflicts_with_all = ["example", "input"])]
+    check: bool,
+
+    /// Save each answer as the known one
+    #[arg(short, long, conflicts_with_all = ["example", "input"])]
+    record: bool,
+
+    /// Directory holding the known answers
+    #[arg(long, value_name = "DIR", default_value = answers::DEFAULT_DIR)]
+    answers: PathBuf,
+
+    /// Number of parts solved at once, the output keeping the requested order
+    #[arg(short, long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: u16,
+
+    /// Report the arithmetic overflows of the solvers as errors, instead of wrapping around
+    #[arg(long)]
+    checked: bool,
+
+    /// Time budget of each part, such as `5s` or `500ms`, past which it fails as timed out
+    /// (a timed-out part that never checks its deadline keeps running in the background,
+    /// so more than `--jobs` parts may then be busy at once)
+    #[arg(long, value_name = "DURATION", value_parser = format::duration)]
+    timeout: Option<Duration>,
+
+    /// How to print the results
+    #[arg(short, long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// Animate how each part is solved, for the days that can be visualized
+    #[arg(long, conflicts_with = "format")]
+    visualize: bool,
+
+    /// Frames per second of the animations
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..), requires = "visualize")]
+    fps: u32,
+
+    /// Write the frames to this directory instead of playing them
+    #[arg(long, value_name = "DIR", requires = "visualize")]
+    export: Option<PathBuf>,
+
+    /// Format of the written frames
+    #[arg(long, value_enum, default_value_t = Export::Png, requires = "export")]
+    export_format: Export,
+
+    /// Size of a cell in the written images, in pixels
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..), requires = "export")]
+    scale: u32,
+}
+
+// A requested part, along with its input
+#[derive(Clone)]
+struct Job {
+    year: u16,
+    day: u8,
+    part: u8,
+    solver: &'static dyn Solver,
+    input: Arc<String>,
+    /// Where the input comes from, to locate its parse errors
+    source: Arc<str>,
+    example: Option<Arc<Example>>,
+}
+
+/// Outcome of a requested part, ready to be printed
+struct Record {
+    year: u16,
+    day: u8,
+    part: u8,
+    /// The answer, or why there is none
+    answer: std::result::Result<Answer, String>,
+    /// The input line a parse error was found on, rendered with the error
+    diagnostic: Option<String>,
+    parse: Duration,
+    solve: Duration,
+    /// Comparison with the expected answer, when there is one to check
+    verdict: Option<Verdict>,
+}
+
+static CSV_HEADER: &str = "year,day,part,answer,parse,solve,status,expected,error";
+
+// Name of the outcome of a check, and the expected answer on a failure
+fn verdict_fields(verdict: &Option<Verdict>) -> (Option<&str>, Option<&str>) {
+    match verdict {
+        Some(Verdict::Pass) => (Some("pass"), None),
+        Some(Verdict::Fail { expected }) => (Some("fail"), Some(expected)),
+        Some(Verdict::Unknown) => (Some("unknown"), None),
+        None => (None, None),
+    }
+}
+
+fn print_table(record: &Record) {
+    println!(
+        "# Solving {} day #{} part {}...",
+        record.year, record.day, record.part
+    );
+    let answer = match &record.answer {
+        Ok(answer) => answer,
+        Err(error) => {
+            match &record.diagnostic {
+                Some(diagnostic) => eprint!("{}", diagnostic),
+                None => eprintln!(" * Error: {}", error),
+            }
+            return;
+        }
+    };
+
+    println!(" * Solution found: {}", answer);
+
+    println!(
+        " * Problem solved in {:.4?}s (parsing {:.4?}s)",
+        (record.parse + record.solve).as_secs_f64(),
+        record.parse.as_secs_f64()
+    );
+
+    match &record.verdict {
+        Some(Verdict::Pass) => println!(" * Check: {}", "PASS".green().bold()),
+        Some(Verdict::Fail { expected }) => {
+            println!(" * Check: {} (expected {})", "FAIL".red().bold(), expected)
+        }
+        Some(Verdict::Unknown) => println!(" * Check: {}", "no known answer".yellow()),
+        None => {}
+    }
+}
+
+fn print_json(records: &[Record]) {
+    let field = |value: Option<&str>| value.map_or("null".to_string(), json_string);
+    let records: Vec<String> = records
+        .iter()
+        .map(|record| {
+            let (status, expected) = verdict_fields(&record.verdict);
+            let answer = record.answer.as_ref().ok().map(Answer::to_string);
+            format!(
+                "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"parse\":{},\"solve\":{},\"status\":{},\"expected\":{},\"error\":{}}}",
+                record.year,
+                record.day,
+                record.part,
+                field(answer.as_deref()),
+                record.parse.as_secs_f64(),
+                record.solve.as_secs_f64(),
+                field(status),
+                field(expected),
+                field(record.answer.as_ref().err().map(String::as_str)),
+            )
+        })
+        .collect();
+    println!("[{}]", records.join(","));
+}
+
+fn print_csv(record: &Record) {
+    let (status, expected) = verdict_fields(&record.verdict);
+    let (answer, error) = match &record.answer {
+        Ok(answer) => (answer.to_string(), String::new()),
+        Err(error) => (String::new(), error.clone()),
+    };
+    println!(
+        "{},{},{},{},{},{},{},{},{}",
+        record.year,
+        record.day,
+        record.part,
+        csv_field(&answer),
+        record.parse.as_secs_f64(),
+        record.solve.as_secs_f64(),
+        status.unwrap_or_default(),
+        csv_field(expected.unwrap_or_default()),
+        csv_field(&error)
+    );
+}
+
+// Plays or writes the animation of a part
+fn visualize(job: &Job, args: &Args) -> Result<()> {
+    let input = job
+        .solver
+        .parse_input(&job.input)
+        .map_err(|error| error.locate(&job.source, &job.input))?;
+    let Some(frames) = job.solver.visualize_input(job.part, input.as_ref()) else {
+        println!(" * Visualization: {}", "none for this day".yellow());
+        return Ok(());
+    };
+    match &args.export {
+        Some(dir) => {
+            let prefix = format!("{}-day{:02}-part{}", job.year, job.day, job.part);
+            let scale = args.scale as usize;
+            let paths = render::export(frames, dir, &prefix, args.export_format, scale)?;
+            println!(
+                " * Visualization: {} frame(s) written to {}",
+                paths.len(),
+                dir.display()
+            );
+        }
+        None => render::play(frames, args.fps, &mut io::stdout())
+            .map_err(|error| Error::Render(error.to_string()))?,
+    }
+    Ok(())
+}
+
+pub fn execute(args: Args, inputs: &Inputs, year: u16) -> ExitCode {
+    let mut status = ExitCode::SUCCESS;
+
+    if args.list {
+        for solver in years::solutions() {
+            let info = solver.info();
+            println!("# {} day #{}: {}", info.year, info.day, info.title);
+        }
+    }
+
+    // The standard input can only be read once, keep it for every day
+    let stdin = match &args.input {
+        Some(path) if path == Path::new(input::STDIN) => Some(input::read(path)),
+        _ => None,
+    };
+
+    // Known answers, loaded once per year
+    let mut known: HashMap<u16, Answers> = HashMap::new();
+
+    // Every requested part, solved once all of them are known
+    let mut jobs: Vec<Job> = Vec::new();
+    for selection in &args.days {
+        let year = selection.year(year);
+        for day in selection.days(&years::registered(year)) {
+            // Retrieve the request day
+            let solver = match years::find(year, day) {
+                Ok(solver) => solver,
+                Err(error) => {
+                    eprintln!("# Error: {}", error);
+                    status = ExitCode::FAILURE;
+                    continue;
+                }
+            };
+
+            // Retrieve the example, giving the expected answers
+            let example: Option<Example> = match (args.example, &args.input) {
+                (Some(n), None) => match examples::get(&args.examples, year, day, n) {
+                    Ok(example) => Some(example),
+                    Err(error) => {
+                        eprintln!("# Error: {}", error);
+                        status = ExitCode::FAILURE;
+                        continue;
+                    }
+                },
+                _ => None,
+            };
+
+            // Retrieve the input
+            let (loaded, source) = match (&stdin, &args.input, &example) {
+                (Some(stdin), _, _) => (stdin.clone(), String::from("<stdin>")),
+                (None, Some(path), _) => (input::read(path), path.display().to_string()),
+                (None, None, Some(example)) => (
+                    Ok(example.input.clone()),
+                    format!(
+                        "{} (example {})",
+                        examples::path(&args.examples, year, day).display(),
+                        args.example.unwrap_or(1)
+                    ),
+                ),
+                (None, None, None) => (
+                    inputs.load(year, day),
+                    inputs.path(year, day).display().to_string(),
+                ),
+            };
+            // Normalized once here, so that the diagnostics quote the lines as parsed
+            let input = match loaded {
+                Ok(input) => Arc::new(preflight::normalize(&input)),
+                Err(error) => {
+                    eprintln!("# Error: {}", error);
+                    status = ExitCode::FAILURE;
+                    continue;
+                }
+            };
+
+            if (args.check || args.record) && !known.contains_key(&year) {
+                match Answers::load(&args.answers, year) {
+                    Ok(answers) => {
+                        known.insert(year, answers);
+                    }
+                    Err(error) => {
+                        eprintln!("# Error: {}", error);
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+
+            let example = example.map(Arc::new);
+            let source: Arc<str> = source.into();
+            for part in selection.parts() {
+                jobs.push(Job {
+                    year,
+                    day,
+                    part,
+                    solver,
+                    input: Arc::clone(&input),
+                    source: Arc::clone(&source),
+                    example: example.clone(),
+                });
+            }
+        }
+    }
+
+    if args.format == Format::Csv {
+        println!("{}", CSV_HEADER);
+    }
+    let mut records: Vec<Record> = Vec::new();
+
+    // Retrieve time
+    let start: Instant = Instant::now();
+    let mut solving = Duration::ZERO;
+
+    let checks = args.checked;
+    let solve = move |job: &Job| {
+        checked::with_checks(checks, || {
+            let start: Instant = Instant::now();
+            let parsed = job
+                .solver
+                .parse_input(&job.input)
+                .map_err(|error| error.locate(&job.source, &job.input));
+            let parse = start.elapsed();
+
+            let start: Instant = Instant::now();
+            let answer =
+                parsed.and_then(|parsed| job.solver.solve_input(job.part, parsed.as_ref()));
+            (answer, parse, start.elapsed())
+        })
+    };
+    runner::run_ordered(
+        &jobs,
+        args.jobs.into(),
+        args.timeout,
+        solve,
+        |job, outcome| {
+            // Compute the solution
+            let (answer, diagnostic, parse, solve) = match outcome {
+                Ok((answer, parse, solve)) => {
+                    let diagnostic = match &answer {
+                        Err(Error::Input(diagnostic)) => Some(diagnostic.render()),
+                        _ => None,
+                    };
+                    (
+                        answer.map_err(|error| error.to_string()),
+                        diagnostic,
+                        parse,
+                        solve,
+                    )
+                }
+                Err(failure) => (
+                    Err(failure.to_string()),
+                    None,
+                    Duration::ZERO,
+                    Duration::ZERO,
+                ),
+            };
+            solving += parse + solve;
+            if answer.is_err() {
+                status = ExitCode::FAILURE;
+            }
+
+            let answers = known.get_mut(&job.year);
+            let verdict = match (&answer, &job.example, &answers) {
+                (Ok(answer), Some(example), _) => {
+                    Some(Verdict::of(example.answer(job.part), answer))
+                }
+                (Ok(answer), None, Some(answers)) if args.check => {
+                    Some(answers.check(job.day, job.part, answer))
+                }
+                _ => None,
+            };
+            if matches!(verdict, Some(Verdict::Fail { .. })) {
+                status = ExitCode::FAILURE;
+            }
+
+            let record = Record {
+                year: job.year,
+                day: job.day,
+                part: job.part,
+                answer,
+                diagnostic,
+                parse,
+                solve,
+                verdict,
+            };
+            match args.format {
+                Format::Table => print_table(&record),
+                Format::Csv => print_csv(&record),
+                Format::Json => {}
+            }
+
+            if let (true, Ok(answer), Some(answers)) = (args.record, &record.answer, answers) {
+                let previous = answers.record(job.day, job.part, answer);
+                if args.format == Format::Table {
+                    match previous {
+                        Some(previous) => println!(
+                            " * Recorded {}",
+                            format!("(replacing {})", previous).yellow()
+                        ),
+                        None => println!(" * Recorded"),
+                    }
+                }
+            }
+            if let (true, Ok(_)) = (args.visualize, &record.answer) {
+                if let Err(error) = checked::with_checks(args.checked, || visualize(job, &args)) {
+                    eprintln!(" * Error: {}", error);
+                    status = ExitCode::FAILURE;
+                }
+            }
+            records.push(record);
+        },
+    );
+
+    match args.format {
+        Format::Table if !jobs.is_empty() => println!(
+            "# {} part(s) run in {:.4?}s, solvers took {:.4?}s in total",
+            jobs.len(),
+            start.elapsed().as_secs_f64(),
+            solving.as_secs_f64()
+        ),
+        Format::Json => print_json(&records),
+        _ => {}
+    }
+
+    if args.record {
+        for answers in known.values() {
+            if let Err(error) = answers.save() {
+                eprintln!("# Error: {}", error);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    status
+}
@@ -0,0 +1,151 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use colored::Colorize;
+
+use aoc::answer::Answer;
+use aoc::error::Error;
+use aoc::utils::answers::{self, Answers};
+use aoc::utils::fetch::{self, AOC_URL, SESSION_ENV};
+use aoc::utils::input::Inputs;
+use aoc::utils::preflight;
+use aoc::utils::submit::{HttpSubmitter, Response, Submissions, Submitter};
+use aoc::years;
+
+use crate::selection::{DaySelection, Days};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// The part to submit: `6:1` or `2023/6:2`
+    part: DaySelection,
+
+    /// Session cookie of the website, defaults to `$AOC_SESSION`
+    #[arg(long)]
+    session: Option<String>,
+
+    /// Base URL of the website, e.g. a local stand-in
+    #[arg(long, default_value = AOC_URL)]
+    url: String,
+
+    /// Directory holding the known answers and the past submissions
+    #[arg(long, value_name = "DIR", default_value = answers::DEFAULT_DIR)]
+    answers: PathBuf,
+}
+
+// Solves the part of `day` on its puzzle input
+fn solve(inputs: &Inputs, year: u16, day: u8, part: u8) -> aoc::error::Result<Answer> {
+    let solver = years::find(year, day)?;
+    // Normalized here, so that the diagnostics quote the lines as parsed
+    let input = preflight::normalize(&inputs.load(year, day)?);
+    let file = inputs.path(year, day).display().to_string();
+    solver
+        .solve(part, &input)
+        .map_err(|error| error.locate(&file, &input))
+}
+
+pub fn execute(args: Args, inputs: &Inputs, year: u16) -> ExitCode {
+    let (day, part) = match args.part {
+        DaySelection {
+            days: Days::Range(start, end),
+            part: Some(part),
+            ..
+        } if start == end => (start, part),
+        _ => {
+            eprintln!("# Error: expected a single day and part, such as `6:1`");
+            return ExitCode::FAILURE;
+        }
+    };
+    let year = args.part.year(year);
+    let Some(session) = fetch::session(args.session) else {
+        eprintln!(
+            "# Error: no session cookie, use `--session` or `${}`",
+            SESSION_ENV
+        );
+        return ExitCode::FAILURE;
+    };
+
+    println!("# Submitting {} day #{} part {}...", year, day, part);
+    let answer = match solve(inputs, year, day, part) {
+        Ok(answer) => answer.to_string(),
+        Err(Error::Input(diagnostic)) => {
+            eprint!("{}", diagnostic.render());
+            return ExitCode::FAILURE;
+        }
+        Err(error) => {
+            eprintln!(" * Error: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    println!(" * Solution found: {}", answer);
+
+    let mut submissions = match Submissions::load(&args.answers, year) {
+        Ok(submissions) => submissions,
+        Err(error) => {
+            eprintln!(" * Error: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let attempts = submissions.get(day, part);
+    if attempts.correct.as_ref() == Some(&answer) {
+        println!(" * Already found {}", "CORRECT".green().bold());
+        return ExitCode::SUCCESS;
+    }
+    if let Some(reason) = attempts.refuse(&answer) {
+        println!(" * Not submitted: {}", reason.yellow());
+        return ExitCode::FAILURE;
+    }
+
+    let submitter = HttpSubmitter::new(args.url, session);
+    let response = match submitter.submit(year, day, part, &answer) {
+        Ok(response) => response,
+        Err(error) => {
+            eprintln!(" * Error: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let status = match &response {
+        Response::Correct => {
+            println!(" * Result: {}", "CORRECT".green().bold());
+            ExitCode::SUCCESS
+        }
+        Response::TooHigh => {
+            println!(" * Result: {} (too high)", "WRONG".red().bold());
+            ExitCode::FAILURE
+        }
+        Response::TooLow => {
+            println!(" * Result: {} (too low)", "WRONG".red().bold());
+            ExitCode::FAILURE
+        }
+        Response::Wrong => {
+            println!(" * Result: {}", "WRONG".red().bold());
+            ExitCode::FAILURE
+        }
+        Response::RateLimited { wait } => {
+            let wait = format!("submitted too recently, retry in {}s", wait.as_secs());
+            println!(" * Result: {}", wait.yellow());
+            ExitCode::FAILURE
+        }
+        Response::WrongLevel => {
+            let message = "the part is already solved or still locked";
+            println!(" * Result: {}", message.yellow());
+            ExitCode::FAILURE
+        }
+    };
+
+    submissions.learn(day, part, &answer, &response);
+    let mut saved = submissions.save();
+    // A correct answer becomes the known one
+    if response == Response::Correct {
+        saved = saved.and_then(|_| {
+            let mut known = Answers::load(&args.answers, year)?;
+            known.record(day, part, &Answer::Text(answer));
+            known.save()
+        });
+    }
+    if let Err(error) = saved {
+        eprintln!(" * Error: {}", error);
+        return ExitCode::FAILURE;
+    }
+
+    status
+}
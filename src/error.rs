@@ -0,0 +1,120 @@
+use std::fmt;
+
+use crate::utils::diagnostic::Diagnostic;
+
+/// Everything that can go wrong while solving a puzzle
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The input file could not be read
+    MissingInput { path: String },
+    /// The input could not be fetched nor stored
+    Fetch(String),
+    /// The input is malformed, `line` and `column` are 1-based.
+    /// `width` is the length of the offending token, 0 when something is missing there.
+    Parse {
+        line: usize,
+        column: usize,
+        width: usize,
+        message: String,
+    },
+    /// A parse error, located in the input it was found in
+    Input(Box<Diagnostic>),
+    /// The answer could not be submitted, or the submissions not stored
+    Submit(String),
+    /// The known answers could not be read nor stored
+    Answers(String),
+    /// The examples of a day are malformed or missing
+    Examples(String),
+    /// The benchmark baseline could not be read nor stored
+    Baseline(String),
+    /// The frames of a visualization could not be exported
+    Render(String),
+    /// A new day could not be added to the sources
+    Scaffold(String),
+    /// An arithmetic operation of a solver overflowed, while checking them
+    Overflow(String),
+    /// The solver gave up, its deadline being past
+    Cancelled,
+    /// The solver does not produce an answer for this part (yet)
+    Unsolved,
+    /// No solution is registered for this day
+    UnknownDay { year: u16, day: u8 },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    // A parse error at a single position
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Error {
+        Error::parse_token(line, column, 0, message)
+    }
+
+    // A parse error on the `width` bytes long token at `column`
+    pub fn parse_token(
+        line: usize,
+        column: usize,
+        width: usize,
+        message: impl Into<String>,
+    ) -> Error {
+        Error::Parse {
+            line,
+            column,
+            width,
+            message: message.into(),
+        }
+    }
+
+    // Locates a parse error in `input`, named `file`, leaving the other errors as they are
+    pub fn locate(self, file: &str, input: &str) -> Error {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                width,
+                message,
+            } => Error::Input(Box::new(Diagnostic {
+                file: file.to_string(),
+                line,
+                column,
+                width,
+                message,
+                text: input
+                    .lines()
+                    .nth(line.wrapping_sub(1))
+                    .unwrap_or_default()
+                    .to_string(),
+            })),
+            error => error,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::MissingInput { path } => write!(f, "could not read input file `{}`", path),
+            Error::Fetch(message) => write!(f, "could not fetch input: {}", message),
+            Error::Parse {
+                line,
+                column,
+                message,
+                ..
+            } => write!(f, "parse error at {}:{}: {}", line, column, message),
+            Error::Input(diagnostic) => write!(f, "parse error in {}", diagnostic),
+            Error::Submit(message) => write!(f, "could not submit the answer: {}", message),
+            Error::Answers(message) => write!(f, "invalid answers file: {}", message),
+            Error::Examples(message) => write!(f, "invalid examples: {}", message),
+            Error::Baseline(message) => write!(f, "invalid benchmark baseline: {}", message),
+            Error::Render(message) => write!(f, "could not export the frames: {}", message),
+            Error::Scaffold(message) => write!(f, "could not add the day: {}", message),
+            Error::Overflow(operation) => write!(f, "arithmetic overflow on `{}`", operation),
+            Error::Cancelled => write!(f, "cancelled past its deadline"),
+            Error::Unsolved => write!(f, "not solved yet"),
+            Error::UnknownDay { year, day } => {
+                write!(f, "day #{} of {} is not implemented", day, year)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,22 @@
+//! Advent of Code solutions, along with the utilities they share.
+//!
+//! Every day is registered in [`years`], and can be solved on any input with [`solve`]:
+//!
+//! ```
+//! let answer = aoc::solve(2024, 1, 1, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap();
+//! assert_eq!(answer.to_string(), "11");
+//! ```
+
+pub mod answer;
+pub mod error;
+pub mod solution;
+pub mod utils;
+pub mod years;
+
+pub use answer::Answer;
+pub use error::{Error, Result};
+
+/// Parses `input` then solves `part` of `day` of `year`
+pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<Answer> {
+    years::find(year, day)?.solve(part, input)
+}
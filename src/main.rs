@@ -1,61 +1,56 @@
-mod days;
-mod utils;
-use std::time::Instant;
+mod commands;
+mod selection;
 
-use clap::Parser;
-use days::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc::utils::input::Inputs;
+use aoc::years;
+use clap::{Parser, Subcommand};
 
 /// Request a day to run
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Cli {
-    /// The requested day to run
-    days: Vec<f32>,
-}
-
-fn get_day_solver(day: i64) -> fn(i64) -> i64 {
-    match day {
-        1 => day01::solve,
-        2 => day02::solve,
-        3 => day03::solve,
-        4 => day04::solve,
-        5 => day05::solve,
-        6 => day06::solve,
-        7 => day07::solve,
-        8 => day08::solve,
-        9 => day09::solve,
-        10 => day10::solve,
-        11 => day11::solve,
-        _ => unimplemented!(),
-    }
-}
-
-fn main() {
-    let args: Cli = Cli::parse();
+    #[command(subcommand)]
+    command: Option<Command>,
 
-    for day in args.days {
-        println!("# Solving day #{}...", day);
+    #[command(flatten)]
+    run: commands::run::Args,
 
-        // Retrieve day and part
-        let [day, part] = [day.trunc() as i64, (day.fract() * 10.0).round() as i64];
+    /// Directory holding the inputs, defaults to `$AOC_INPUTS` then `inputs`
+    #[arg(long, value_name = "DIR", global = true)]
+    inputs: Option<PathBuf>,
 
-        if part < 0 || part > 2 {
-            panic!(" * Incorrect part value ({}). Must be 1 or 2.", part)
-        }
-
-        // Retrieve the request day
-        let problem: fn(i64) -> i64 = get_day_solver(day);
-
-        // Retrieve time
-        let start: Instant = Instant::now();
-
-        // Compute the solution
-        let solution: i64 = problem(part);
+    /// Year of the days without one, defaults to the latest
+    #[arg(short, long, global = true)]
+    year: Option<u16>,
+}
 
-        println!(" * Solution found: {}", solution);
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the requested days (the default command)
+    Run(commands::run::Args),
+    /// Populate the inputs directory with the missing puzzle inputs
+    Fetch(commands::fetch::Args),
+    /// Time the requested days over several runs
+    Bench(commands::bench::Args),
+    /// Add a day to the sources, from the template
+    New(commands::new::Args),
+    /// Submit the answer of a part to the website
+    Submit(commands::submit::Args),
+}
 
-        println!(
-            " * Problem solved in {:.4?}s",
-            start.elapsed().as_secs_f64()
-        )
+fn main() -> ExitCode {
+    let args: Cli = Cli::parse();
+    let inputs = Inputs::new(args.inputs);
+    let year: u16 = args.year.unwrap_or_else(years::latest);
+
+    match args.command.unwrap_or(Command::Run(args.run)) {
+        Command::Run(args) => commands::run::execute(args, &inputs, year),
+        Command::Fetch(args) => commands::fetch::execute(args, &inputs, year),
+        Command::Bench(args) => commands::bench::execute(args, &inputs, year),
+        Command::New(args) => commands::new::execute(args, &inputs, year),
+        Command::Submit(args) => commands::submit::execute(args, &inputs, year),
     }
 }
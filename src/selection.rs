@@ -0,0 +1,175 @@
+use std::str::FromStr;
+
+/// Days targeted by a selector
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Days {
+    /// Every registered day
+    All,
+    /// The latest registered day
+    Last,
+    /// Every day from `start` to `end`, both included
+    Range(u8, u8),
+}
+
+/// A day/part selector, as given on the command line.
+///
+/// Grammar: `[<year>/]<days>[:<part>]` where `<days>` is one of `6`, `1..=11`, `1..12`, `all` or `last`.
+/// Without a year, the default one is used. Without a part, both parts are run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DaySelection {
+    pub year: Option<u16>,
+    pub days: Days,
+    pub part: Option<u8>,
+}
+
+impl DaySelection {
+    pub fn year(&self, default: u16) -> u16 {
+        self.year.unwrap_or(default)
+    }
+
+    // Expands the selection against the `registered` days (sorted).
+    // A single day is kept even if not registered, so that it can be reported.
+    pub fn days(&self, registered: &[u8]) -> Vec<u8> {
+        match self.days {
+            Days::All => registered.to_vec(),
+            Days::Last => registered.last().copied().into_iter().collect(),
+            Days::Range(start, end) if start == end => vec![start],
+            Days::Range(start, end) => registered
+                .iter()
+                .copied()
+                .filter(|day| (start..=end).contains(day))
+                .collect(),
+        }
+    }
+
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+fn parse_year(s: &str) -> Result<u16, String> {
+    match s.parse::<u16>() {
+        Ok(year) if year >= 2015 => Ok(year),
+        _ => Err(format!("`{}` is not a year, expected 2015 or later", s)),
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("`{}` is not a day, expected 1 to 25", s)),
+    }
+}
+
+// The day after the last one of an exclusive range
+fn parse_end(s: &str) -> Result<u8, String> {
+    match s.parse::<u8>() {
+        Ok(end) if (2..=26).contains(&end) => Ok(end),
+        _ => Err(format!(
+            "`{}` is not the end of a range, expected 2 to 26",
+            s
+        )),
+    }
+}
+
+fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("`{}` is not a part, expected 1 or 2", s)),
+    }
+}
+
+fn parse_days(s: &str) -> Result<Days, String> {
+    let (start, end) = match s {
+        "all" => return Ok(Days::All),
+        "last" => return Ok(Days::Last),
+        _ => match (s.split_once("..="), s.split_once("..")) {
+            (Some((start, end)), _) => (parse_day(start)?, parse_day(end)?),
+            // Exclusive range
+            (None, Some((start, end))) => {
+                let (start, end) = (parse_day(start)?, parse_end(end)?);
+                if end <= start {
+                    return Err(format!("`{}` is an empty range", s));
+                }
+                (start, end - 1)
+            }
+            (None, None) => {
+                let day = parse_day(s)?;
+                (day, day)
+            }
+        },
+    };
+    if end < start {
+        return Err(format!("`{}` is an empty range", s));
+    }
+    Ok(Days::Range(start, end))
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, s) = match s.split_once('/') {
+            Some((year, s)) => (Some(parse_year(year)?), s),
+            None => (None, s),
+        };
+        let (days, part) = match s.split_once(':') {
+            Some((days, part)) => (days, Some(parse_part(part)?)),
+            None => (s, None),
+        };
+        Ok(DaySelection {
+            year,
+            days: parse_days(days)?,
+            part,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_selectors() {
+        let cases = [
+            ("6", None, Days::Range(6, 6), None),
+            ("6:2", None, Days::Range(6, 6), Some(2)),
+            ("1..=11", None, Days::Range(1, 11), None),
+            ("1..12:1", None, Days::Range(1, 11), Some(1)),
+            ("1..26", None, Days::Range(1, 25), None),
+            ("all", None, Days::All, None),
+            ("last:2", None, Days::Last, Some(2)),
+            ("2023/6:1", Some(2023), Days::Range(6, 6), Some(1)),
+            ("2024/all", Some(2024), Days::All, None),
+        ];
+        for (selector, year, days, part) in cases {
+            let expected = DaySelection { year, days, part };
+            assert_eq!(selector.parse(), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn reject_invalid_selectors() {
+        for selector in [
+            "", "0", "26", "6:3", "6.1", "11..=1", "4..4", "1..27", "1..=26", "first", "2014/6",
+            "x/6", "2024/",
+        ] {
+            assert!(selector.parse::<DaySelection>().is_err(), "{}", selector);
+        }
+    }
+
+    #[test]
+    fn expand_days() {
+        let registered = [1, 2, 3, 5];
+        let range: DaySelection = "2..=4".parse().unwrap();
+        assert_eq!(range.days(&registered), vec![2, 3]);
+        let last: DaySelection = "last".parse().unwrap();
+        assert_eq!(last.days(&registered), vec![5]);
+        let unknown: DaySelection = "4".parse().unwrap();
+        assert_eq!(unknown.days(&registered), vec![4]);
+    }
+}
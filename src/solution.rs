@@ -0,0 +1,95 @@
+use std::any::Any;
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::utils::preflight::{self, Shape};
+use crate::utils::render::Frames;
+
+/// Metadata describing a puzzle
+#[derive(Clone, Copy)]
+pub struct Info {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+}
+
+/// A puzzle solution: the input is parsed once, then handed to both parts
+pub trait Solution {
+    /// The parsed puzzle input
+    type Input;
+
+    fn info(&self) -> Info;
+
+    // What the input must look like, checked before `parse` is given it
+    fn shape(&self) -> Shape {
+        Shape::Any
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer>;
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer>;
+
+    // Frames showing how the requested part is solved, for the days that can be visualized
+    fn visualize<'a>(&self, _input: &'a Self::Input, _part: u8) -> Option<Frames<'a>> {
+        None
+    }
+}
+
+/// Object-safe view of a `Solution`, as stored in the registry
+pub trait Solver: Sync {
+    fn info(&self) -> Info;
+
+    // Normalizes and checks the raw `input`, then parses it into the solution's own input type
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    // Solves the requested part on an input returned by `parse_input`
+    fn solve_input(&self, part: u8, input: &dyn Any) -> Result<Answer>;
+
+    // Visualizes the requested part on an input returned by `parse_input`
+    fn visualize_input<'a>(&self, part: u8, input: &'a dyn Any) -> Option<Frames<'a>>;
+
+    // Parses the raw `input` then solves the requested part
+    fn solve(&self, part: u8, input: &str) -> Result<Answer> {
+        self.solve_input(part, self.parse_input(input)?.as_ref())
+    }
+}
+
+// The input of `S`, as returned by its `parse_input`
+fn downcast<S>(input: &dyn Any) -> &S::Input
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    input
+        .downcast_ref::<S::Input>()
+        .expect("input parsed by another solver")
+}
+
+impl<S> Solver for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn info(&self) -> Info {
+        Solution::info(self)
+    }
+
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>> {
+        let input = preflight::prepare(input, self.shape())?;
+        Ok(Box::new(self.parse(&input)?))
+    }
+
+    fn solve_input(&self, part: u8, input: &dyn Any) -> Result<Answer> {
+        match part {
+            1 => self.part1(downcast::<S>(input)),
+            2 => self.part2(downcast::<S>(input)),
+            _ => Err(Error::Unsolved),
+        }
+    }
+
+    fn visualize_input<'a>(&self, part: u8, input: &'a dyn Any) -> Option<Frames<'a>> {
+        self.visualize(downcast::<S>(input), part)
+    }
+}
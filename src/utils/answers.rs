@@ -0,0 +1,112 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::utils::store::Store;
+
+/// Directory holding the known answers when nothing else is configured
+pub static DEFAULT_DIR: &str = "answers";
+
+/// Outcome of checking an answer against the known one
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// No answer is known for this part yet
+    Unknown,
+}
+
+/// Known-correct answers of a year, stored in `<dir>/<year>.toml` as:
+///
+/// ```toml
+/// [day06]
+/// part1 = "41"
+/// part2 = "6"
+/// ```
+pub struct Answers {
+    days: Store<BTreeMap<String, BTreeMap<String, String>>>,
+}
+
+impl Verdict {
+    // Compares `answer` with the `expected` one, if known
+    pub fn of(expected: Option<&str>, answer: &Answer) -> Verdict {
+        match expected {
+            Some(expected) if expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+pub fn day_key(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+pub fn part_key(part: u8) -> String {
+    format!("part{}", part)
+}
+
+impl Answers {
+    // Loads the answers of `year`, starting empty if none were recorded yet
+    pub fn load(dir: &Path, year: u16) -> Result<Answers> {
+        let days = Store::load(dir.join(format!("{}.toml", year)), Error::Answers)?;
+        Ok(Answers { days })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.days
+            .get(&day_key(day))
+            .and_then(|parts| parts.get(&part_key(part)))
+            .map(|answer| answer.as_str())
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        Verdict::of(self.get(day, part), answer)
+    }
+
+    // Records `answer`, returning the previously known one if it differs
+    pub fn record(&mut self, day: u8, part: u8, answer: &Answer) -> Option<String> {
+        let previous = self
+            .days
+            .entry(day_key(day))
+            .or_default()
+            .insert(part_key(part), answer.to_string());
+        previous.filter(|previous| *previous != answer.to_string())
+    }
+
+    pub fn save(&self) -> Result<()> {
+        self.days.save()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::TempDir;
+
+    #[test]
+    fn record_then_check() {
+        let dir = TempDir::new("answers");
+
+        let mut answers = Answers::load(&dir, 2024).unwrap();
+        assert_eq!(answers.check(6, 1, &Answer::Int(41)), Verdict::Unknown);
+        assert_eq!(answers.record(6, 1, &Answer::Int(41)), None);
+        answers.save().unwrap();
+
+        let mut answers = Answers::load(&dir, 2024).unwrap();
+        assert_eq!(answers.check(6, 1, &Answer::Int(41)), Verdict::Pass);
+        let expected = Verdict::Fail {
+            expected: "41".to_string(),
+        };
+        assert_eq!(answers.check(6, 1, &Answer::Int(42)), expected);
+        assert_eq!(
+            answers.record(6, 1, &Answer::Int(42)),
+            Some("41".to_string())
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::solution::Solver;
+use crate::utils::answers::{day_key, part_key};
+use crate::utils::store::Store;
+
+/// Directory holding the benchmark baselines when nothing else is configured
+pub static DEFAULT_DIR: &str = "benches";
+
+/// Summary of a series of timings, in seconds
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    // Summarizes `samples`, which must not be empty
+    pub fn new(samples: &[Duration]) -> Stats {
+        let mut seconds: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+        seconds.sort_by(f64::total_cmp);
+
+        let count = seconds.len() as f64;
+        let middle = seconds.len() / 2;
+        let median = if seconds.len().is_multiple_of(2) {
+            (seconds[middle - 1] + seconds[middle]) / 2.0
+        } else {
+            seconds[middle]
+        };
+        let mean = seconds.iter().sum::<f64>() / count;
+        let variance = seconds.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / count;
+
+        Stats {
+            min: seconds[0],
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// Timings of a day part over several runs
+pub struct Measure {
+    pub answer: Answer,
+    pub parse: Stats,
+    pub solve: Stats,
+    /// Parsing and solving together
+    pub total: Stats,
+}
+
+// Runs `part` of `solver` on `input` `warmup` times untimed, then `runs` times timed.
+// Parsing is timed apart from solving, `runs` must not be zero.
+pub fn measure(
+    solver: &dyn Solver,
+    part: u8,
+    input: &str,
+    warmup: usize,
+    runs: usize,
+) -> Result<Measure> {
+    for _ in 0..warmup {
+        solver.solve(part, input)?;
+    }
+
+    let mut parse: Vec<Duration> = Vec::with_capacity(runs);
+    let mut solve: Vec<Duration> = Vec::with_capacity(runs);
+    let mut answer: Option<Answer> = None;
+    for _ in 0..runs {
+        let start: Instant = Instant::now();
+        let parsed = solver.parse_input(input)?;
+        parse.push(start.elapsed());
+
+        let start: Instant = Instant::now();
+        answer = Some(solver.solve_input(part, parsed.as_ref())?);
+        solve.push(start.elapsed());
+    }
+    let total: Vec<Duration> = parse.iter().zip(&solve).map(|(p, s)| *p + *s).collect();
+
+    Ok(Measure {
+        answer: answer.expect("at least one run"),
+        parse: Stats::new(&parse),
+        solve: Stats::new(&solve),
+        total: Stats::new(&total),
+    })
+}
+
+/// Outcome of comparing a timing with the baseline
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Comparison {
+    /// Not slower than the baseline by more than the tolerance
+    Within { baseline: f64 },
+    /// Slower than the baseline by more than the tolerance
+    Regressed { baseline: f64 },
+    /// No baseline is known for this part yet
+    Unknown,
+}
+
+/// Median total timings of a year, in seconds, stored in `<dir>/<year>.toml` as below.
+/// Timings on the n-th example are kept apart, in `<dir>/<year>.example<n>.toml`.
+///
+/// ```toml
+/// [day06]
+/// part1 = 0.0012
+/// part2 = 0.45
+/// ```
+pub struct Baseline {
+    days: Store<BTreeMap<String, BTreeMap<String, f64>>>,
+}
+
+impl Baseline {
+    // Loads the baseline of `year` on its puzzle inputs or on its `example`-th examples,
+    // starting empty if none was recorded yet
+    pub fn load(dir: &Path, year: u16, example: Option<u8>) -> Result<Baseline> {
+        let path = match example {
+            Some(n) => dir.join(format!("{}.example{}.toml", year, n)),
+            None => dir.join(format!("{}.toml", year)),
+        };
+        let days = Store::load(path, Error::Baseline)?;
+        Ok(Baseline { days })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<f64> {
+        self.days
+            .get(&day_key(day))
+            .and_then(|parts| parts.get(&part_key(part)))
+            .copied()
+    }
+
+    // A part regresses when `median` exceeds the baseline by more than `tolerance` percent
+    pub fn compare(&self, day: u8, part: u8, median: f64, tolerance: f64) -> Comparison {
+        match self.get(day, part) {
+            Some(baseline) if median > baseline * (1.0 + tolerance / 100.0) => {
+                Comparison::Regressed { baseline }
+            }
+            Some(baseline) => Comparison::Within { baseline },
+            None => Comparison::Unknown,
+        }
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, median: f64) {
+        self.days
+            .entry(day_key(day))
+            .or_default()
+            .insert(part_key(part), median);
+    }
+
+    pub fn save(&self) -> Result<()> {
+        self.days.save()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::TempDir;
+
+    #[test]
+    fn summarize() {
+        let samples: Vec<Duration> = [4, 1, 3, 2].into_iter().map(Duration::from_secs).collect();
+        let stats = Stats::new(&samples);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 2.5);
+        assert_eq!(stats.mean, 2.5);
+        assert_eq!(stats.stddev, 1.25_f64.sqrt());
+
+        let stats = Stats::new(&samples[..3]);
+        assert_eq!(stats.median, 3.0);
+    }
+
+    #[test]
+    fn compare_with_baseline() {
+        let dir = TempDir::new("bench");
+
+        let mut baseline = Baseline::load(&dir, 2024, None).unwrap();
+        assert_eq!(baseline.compare(6, 2, 1.0, 10.0), Comparison::Unknown);
+        baseline.record(6, 2, 1.0);
+        baseline.save().unwrap();
+
+        let baseline = Baseline::load(&dir, 2024, None).unwrap();
+        let within = Comparison::Within { baseline: 1.0 };
+        assert_eq!(baseline.compare(6, 2, 1.05, 10.0), within);
+        let regressed = Comparison::Regressed { baseline: 1.0 };
+        assert_eq!(baseline.compare(6, 2, 1.2, 10.0), regressed);
+
+        // Timings on the examples are not compared with those on the puzzle inputs
+        let baseline = Baseline::load(&dir, 2024, Some(1)).unwrap();
+        assert_eq!(baseline.compare(6, 2, 1.0, 10.0), Comparison::Unknown);
+    }
+}
@@ -0,0 +1,96 @@
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+use crate::utils::fetch::Fetcher;
+use crate::utils::input::Inputs;
+
+/// Minimum delay between two fetches, to stay polite with the server
+pub static FETCH_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Where a cached input came from
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    /// Already on disk, nothing was fetched
+    Cached,
+    /// Fetched then stored on disk
+    Fetched,
+}
+
+/// Stores fetched puzzle inputs under `<inputs>/<year>/`, fetching each one at most once
+pub struct Cache<'a> {
+    inputs: &'a Inputs,
+    fetcher: Box<dyn Fetcher>,
+    interval: Duration,
+    last_fetch: Option<Instant>,
+}
+
+impl<'a> Cache<'a> {
+    pub fn new(inputs: &'a Inputs, fetcher: Box<dyn Fetcher>, interval: Duration) -> Cache<'a> {
+        Cache {
+            inputs,
+            fetcher,
+            interval,
+            last_fetch: None,
+        }
+    }
+
+    // Ensures the puzzle input of `day` is on disk, returning its path
+    pub fn get(&mut self, year: u16, day: u8) -> Result<(PathBuf, Status)> {
+        let path = self.inputs.path(year, day);
+        // Never fetch an existing input again
+        if path.exists() {
+            return Ok((path, Status::Cached));
+        }
+
+        // Wait until the previous fetch is far enough
+        if let Some(last_fetch) = self.last_fetch {
+            let elapsed = last_fetch.elapsed();
+            if elapsed < self.interval {
+                thread::sleep(self.interval - elapsed);
+            }
+        }
+        self.last_fetch = Some(Instant::now());
+        let input = self.fetcher.fetch(year, day)?;
+
+        let write = |path: &PathBuf| -> std::io::Result<()> {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, input)
+        };
+        write(&path).map_err(|error| Error::Fetch(format!("{}: {}", path.display(), error)))?;
+        Ok((path, Status::Fetched))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::fetch::DirFetcher;
+    use crate::utils::testing::TempDir;
+
+    #[test]
+    fn fetch_once() {
+        let dir = TempDir::new("cache");
+        let (fixtures, cached) = (dir.join("fixtures"), dir.join("inputs"));
+        fs::create_dir_all(fixtures.join("2024")).unwrap();
+        fs::write(fixtures.join("2024").join("day06.txt"), "....#\n").unwrap();
+
+        let inputs = Inputs::new(Some(cached.clone()));
+        let fetcher = DirFetcher::new(fixtures.clone());
+        let mut cache = Cache::new(&inputs, Box::new(fetcher), Duration::ZERO);
+
+        let (path, status) = cache.get(2024, 6).unwrap();
+        assert_eq!(status, Status::Fetched);
+        assert_eq!(path, cached.join("2024").join("day06.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "....#\n");
+
+        // The fixture is gone, the stored input must be used
+        fs::remove_dir_all(&fixtures).unwrap();
+        let (_, status) = cache.get(2024, 6).unwrap();
+        assert_eq!(status, Status::Cached);
+    }
+}
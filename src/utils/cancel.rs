@@ -0,0 +1,56 @@
+use std::cell::Cell;
+use std::time::Instant;
+
+use crate::error::{Error, Result};
+
+thread_local! {
+    // When the task running on this thread should give up, if ever
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+// Runs `task` with `deadline` set for the current thread, then restores the previous one
+pub fn with_deadline<R>(deadline: Option<Instant>, task: impl FnOnce() -> R) -> R {
+    let previous = DEADLINE.replace(deadline);
+    let result = task();
+    DEADLINE.set(previous);
+    result
+}
+
+// The deadline of the current thread, to hand over to the threads it spawns
+pub fn deadline() -> Option<Instant> {
+    DEADLINE.get()
+}
+
+// Whether the deadline of the current thread is past
+pub fn cancelled() -> bool {
+    deadline().is_some_and(|deadline| Instant::now() >= deadline)
+}
+
+// Fails once the deadline of the current thread is past.
+// Long-running solvers call it now and then, so that they stop once they ran out of time.
+pub fn check() -> Result<()> {
+    match cancelled() {
+        true => Err(Error::Cancelled),
+        false => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn check_deadlines() {
+        assert_eq!(check(), Ok(()));
+        let past = Instant::now();
+        let future = past + Duration::from_secs(60);
+        with_deadline(Some(future), || {
+            assert_eq!(check(), Ok(()));
+            with_deadline(Some(past), || assert_eq!(check(), Err(Error::Cancelled)));
+            assert_eq!(deadline(), Some(future));
+        });
+        assert_eq!(deadline(), None);
+    }
+}
@@ -0,0 +1,159 @@
+use std::cell::Cell;
+use std::fmt;
+
+use crate::error::{Error, Result};
+
+thread_local! {
+    // Whether the operations below report overflows on this thread, rather than wrapping around
+    static ENABLED: Cell<bool> = const { Cell::new(false) };
+}
+
+// Runs `task` reporting its overflows as errors or not, then restores the previous mode
+pub fn with_checks<R>(enabled: bool, task: impl FnOnce() -> R) -> R {
+    let previous = ENABLED.replace(enabled);
+    let result = task();
+    ENABLED.set(previous);
+    result
+}
+
+// Whether overflows are reported on the current thread, to hand over to the threads it spawns
+pub fn enabled() -> bool {
+    ENABLED.get()
+}
+
+/// A primitive integer, with both its checked and wrapping operations
+pub trait Int: Copy + fmt::Display {
+    const ZERO: Self;
+    const TEN: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_pow(self, exponent: u32) -> Option<Self>;
+    fn wrapping_add(self, other: Self) -> Self;
+    fn wrapping_mul(self, other: Self) -> Self;
+    fn wrapping_pow(self, exponent: u32) -> Self;
+    // Number of decimal digits, for a non-negative integer
+    fn digits(self) -> u32;
+}
+
+macro_rules! int {
+    ($($int:ty),*) => {
+        $(
+            impl Int for $int {
+                const ZERO: Self = 0;
+                const TEN: Self = 10;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$int>::checked_add(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$int>::checked_mul(self, other)
+                }
+
+                fn checked_pow(self, exponent: u32) -> Option<Self> {
+                    <$int>::checked_pow(self, exponent)
+                }
+
+                fn wrapping_add(self, other: Self) -> Self {
+                    <$int>::wrapping_add(self, other)
+                }
+
+                fn wrapping_mul(self, other: Self) -> Self {
+                    <$int>::wrapping_mul(self, other)
+                }
+
+                fn wrapping_pow(self, exponent: u32) -> Self {
+                    <$int>::wrapping_pow(self, exponent)
+                }
+
+                fn digits(self) -> u32 {
+                    self.checked_ilog10().map_or(1, |digits| digits + 1)
+                }
+            }
+        )*
+    };
+}
+
+int!(i64, u64, usize, i128, u128);
+
+// The `exact` result, or the `wrapped` one when it overflowed and overflows are not reported
+fn check<T>(
+    exact: Option<T>,
+    wrapped: impl FnOnce() -> T,
+    operation: impl FnOnce() -> String,
+) -> Result<T> {
+    match exact {
+        Some(value) => Ok(value),
+        None if enabled() => Err(Error::Overflow(operation())),
+        None => Ok(wrapped()),
+    }
+}
+
+pub fn add<T: Int>(a: T, b: T) -> Result<T> {
+    check(
+        a.checked_add(b),
+        || a.wrapping_add(b),
+        || format!("{} + {}", a, b),
+    )
+}
+
+pub fn mul<T: Int>(a: T, b: T) -> Result<T> {
+    check(
+        a.checked_mul(b),
+        || a.wrapping_mul(b),
+        || format!("{} * {}", a, b),
+    )
+}
+
+// The digits of `a` followed by the ones of `b`, which must not be negative
+pub fn concat<T: Int>(a: T, b: T) -> Result<T> {
+    let digits = b.digits();
+    check(
+        T::TEN
+            .checked_pow(digits)
+            .and_then(|shift| a.checked_mul(shift))
+            .and_then(|shifted| shifted.checked_add(b)),
+        || a.wrapping_mul(T::TEN.wrapping_pow(digits)).wrapping_add(b),
+        || format!("{} || {}", a, b),
+    )
+}
+
+pub fn sum<T: Int>(values: impl IntoIterator<Item = T>) -> Result<T> {
+    values.into_iter().try_fold(T::ZERO, add)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_overflows() {
+        assert_eq!(concat(12i64, 345), Ok(12345));
+        assert_eq!(concat(7u64, 0), Ok(70));
+        assert_eq!(sum([1usize, 2, 3]), Ok(6));
+        assert_eq!(add(u64::MAX, 1), Ok(0));
+        assert_eq!(mul(i64::MAX, 2), Ok(-2));
+
+        let overflows = with_checks(true, || {
+            (
+                add(u64::MAX, 1),
+                mul(i64::MAX, 2),
+                concat(i64::MAX / 10, 10),
+                sum([usize::MAX, 1]),
+                mul(1u128 << 64, 1 << 63),
+            )
+        });
+        assert!(!enabled());
+        assert_eq!(
+            overflows,
+            (
+                Err(Error::Overflow(format!("{} + 1", u64::MAX))),
+                Err(Error::Overflow(format!("{} * 2", i64::MAX))),
+                Err(Error::Overflow(format!("{} || 10", i64::MAX / 10))),
+                Err(Error::Overflow(format!("{} + 1", usize::MAX))),
+                Ok(1 << 127),
+            )
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a sequence of states starts repeating itself
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// Number of steps before the first state of the cycle
+    pub offset: usize,
+    /// Number of steps before a state of the cycle comes back
+    pub length: usize,
+}
+
+impl Cycle {
+    // Index of the first state equal to the `n`-th one
+    pub fn reduce(&self, n: usize) -> usize {
+        match n < self.offset {
+            true => n,
+            false => self.offset + (n - self.offset) % self.length,
+        }
+    }
+}
+
+// Follows `step` from `start` until a state comes back, remembering every state on the way.
+// `None` when `step` ends the sequence first.
+pub fn find<S>(start: S, mut step: impl FnMut(&S) -> Option<S>) -> Option<Cycle>
+where
+    S: Clone + Eq + Hash,
+{
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut state = start;
+    for i in 0.. {
+        if let Some(&offset) = seen.get(&state) {
+            return Some(Cycle {
+                offset,
+                length: i - offset,
+            });
+        }
+        let next = step(&state)?;
+        seen.insert(state, i);
+        state = next;
+    }
+    unreachable!()
+}
+
+// As `find`, in constant memory, with Brent's algorithm
+pub fn brent<S>(start: S, mut step: impl FnMut(&S) -> Option<S>) -> Option<Cycle>
+where
+    S: Clone + Eq,
+{
+    // Find the length: the hare moves on while the tortoise waits at each power of two
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(&start)?;
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare)?;
+        length += 1;
+    }
+
+    // Then the offset: both move on `length` steps apart until they meet
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..length {
+        hare = step(&hare)?;
+    }
+    let mut offset = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        offset += 1;
+    }
+    Some(Cycle { offset, length })
+}
+
+// As `find`, in constant memory, with Floyd's algorithm
+pub fn floyd<S>(start: S, mut step: impl FnMut(&S) -> Option<S>) -> Option<Cycle>
+where
+    S: Clone + Eq,
+{
+    // The hare moves twice as fast as the tortoise, until it catches it up in the cycle
+    let mut tortoise = step(&start)?;
+    let mut hare = step(&tortoise)?;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        let half = step(&hare)?;
+        hare = step(&half)?;
+    }
+
+    // The start is as far from the cycle as their meeting point, modulo its length
+    let mut tortoise = start;
+    let mut offset = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        offset += 1;
+    }
+
+    let mut length = 1;
+    let mut hare = step(&tortoise)?;
+    while tortoise != hare {
+        hare = step(&hare)?;
+        length += 1;
+    }
+    Some(Cycle { offset, length })
+}
+
+// The state after `n` steps from `start`, skipping the repetitions once a cycle is found.
+// `None` when `step` ends the sequence first.
+pub fn nth<S>(start: S, mut step: impl FnMut(&S) -> Option<S>, n: usize) -> Option<S>
+where
+    S: Clone + Eq + Hash,
+{
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut states: Vec<S> = Vec::new();
+    let mut state = start;
+    for i in 0..n {
+        if let Some(&offset) = seen.get(&state) {
+            let cycle = Cycle {
+                offset,
+                length: i - offset,
+            };
+            return Some(states.swap_remove(cycle.reduce(n)));
+        }
+        let next = step(&state)?;
+        seen.insert(state.clone(), i);
+        states.push(state);
+        state = next;
+    }
+    Some(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // x -> x² + 1 modulo 255 from 3: 3, 10, 101, 2, 5, 26, 167, 95, 101, ...
+    fn square(x: &u64) -> Option<u64> {
+        Some((x * x + 1) % 255)
+    }
+
+    #[test]
+    fn detect_cycles() {
+        let expected = Some(Cycle {
+            offset: 2,
+            length: 6,
+        });
+        assert_eq!(find(3, square), expected);
+        assert_eq!(brent(3, square), expected);
+        assert_eq!(floyd(3, square), expected);
+
+        // A cycle from the start
+        let turn = |x: &u8| Some((x + 1) % 4);
+        let expected = Some(Cycle {
+            offset: 0,
+            length: 4,
+        });
+        assert_eq!(find(0, turn), expected);
+        assert_eq!(brent(0, turn), expected);
+        assert_eq!(floyd(0, turn), expected);
+
+        // A sequence ending before any repetition
+        let count = |x: &u8| x.checked_add(1);
+        assert_eq!(find(0, count), None);
+        assert_eq!(brent(0, count), None);
+        assert_eq!(floyd(0, count), None);
+    }
+
+    #[test]
+    fn skip_repetitions() {
+        assert_eq!(nth(3, square, 0), Some(3));
+        assert_eq!(nth(3, square, 7), Some(95));
+        // 1_000_000_000 - 2 = 6 * 166_666_666 + 2 steps into the cycle, on 101, 2, 5, ...
+        assert_eq!(nth(3, square, 1_000_000_000), Some(5));
+        assert_eq!(nth(0, |x: &u8| x.checked_add(1), 300), None);
+
+        let cycle = Cycle {
+            offset: 2,
+            length: 6,
+        };
+        assert_eq!(cycle.reduce(1), 1);
+        assert_eq!(cycle.reduce(8), 2);
+        assert_eq!(cycle.reduce(17), 5);
+    }
+}
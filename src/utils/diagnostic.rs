@@ -0,0 +1,105 @@
+use std::fmt;
+
+/// A parse error located in a named input, along with the line it was found on
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Name of the input: its path, or what stands for it
+    pub file: String,
+    /// 1-based line and column (in bytes) of the error
+    pub line: usize,
+    pub column: usize,
+    /// Length in bytes of the offending token, 0 when something is missing there
+    pub width: usize,
+    pub message: String,
+    /// The whole line the error was found on
+    pub text: String,
+}
+
+impl Diagnostic {
+    // The offending token, empty when something is missing there
+    pub fn snippet(&self) -> &str {
+        let start = self.column.saturating_sub(1);
+        self.text.get(start..start + self.width).unwrap_or_default()
+    }
+
+    // The error, then the line it was found on with a caret under the offending token:
+    //
+    // error: `4x` is not a valid number
+    //  --> inputs/2024/day05.txt:3:4
+    //   |
+    // 3 | 75,4x,61
+    //   |    ^^
+    pub fn render(&self) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        // Columns are counted in bytes, carets in chars
+        let before = self
+            .text
+            .get(..self.column.saturating_sub(1))
+            .map_or(0, |text| text.chars().count());
+        let carets = self.snippet().chars().count().max(1);
+        format!(
+            "error: {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}\n",
+            self.message,
+            gutter,
+            self.file,
+            self.line,
+            self.column,
+            gutter,
+            number,
+            self.text,
+            gutter,
+            " ".repeat(before),
+            "^".repeat(carets)
+        )
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {}",
+            self.file, self.line, self.column, self.message
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_diagnostics() {
+        let mut diagnostic = Diagnostic {
+            file: String::from("inputs/2024/day05.txt"),
+            line: 3,
+            column: 4,
+            width: 2,
+            message: String::from("`4x` is not a valid number"),
+            text: String::from("75,4x,61"),
+        };
+        assert_eq!(diagnostic.snippet(), "4x");
+        assert_eq!(
+            diagnostic.to_string(),
+            "inputs/2024/day05.txt:3:4: `4x` is not a valid number"
+        );
+        assert_eq!(
+            diagnostic.render(),
+            "error: `4x` is not a valid number\n \
+             --> inputs/2024/day05.txt:3:4\n  \
+             |\n\
+             3 | 75,4x,61\n  \
+             |    ^^\n"
+        );
+
+        // Something missing at the end of the line
+        diagnostic.column = 9;
+        diagnostic.width = 0;
+        diagnostic.message = String::from("expected `|`");
+        assert_eq!(diagnostic.snippet(), "");
+        assert!(diagnostic
+            .render()
+            .ends_with("3 | 75,4x,61\n  |         ^\n"));
+    }
+}
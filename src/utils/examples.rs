@@ -0,0 +1,119 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+/// Directory holding the examples when nothing else is configured, at the root of this crate
+pub static DEFAULT_DIR: &str = "examples";
+
+/// An example given in a puzzle statement, with the answers given for it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    answers: [Option<String>; 2],
+}
+
+impl Example {
+    // The expected answer of `part`, if the statement gives one for this example
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        match part {
+            1 | 2 => self.answers[part as usize - 1].as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Path of the examples of a day, `<dir>/<year>/dayNN.toml`, laid out as:
+///
+/// ```toml
+/// [[example]]
+/// input = '''
+/// 3   4
+/// 4   3
+/// '''
+/// part1 = "11"
+/// part2 = "31"
+/// ```
+///
+/// An example only gives the answers of the parts it applies to.
+pub fn path(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string())
+        .join(format!("day{:02}.toml", day))
+}
+
+// Reads the examples of `day`, in order
+pub fn load(dir: &Path, year: u16, day: u8) -> Result<Vec<Example>> {
+    let path = path(dir, year, day);
+    let content = fs::read_to_string(&path).map_err(|_| Error::MissingInput {
+        path: path.display().to_string(),
+    })?;
+    parse(&content).map_err(|message| Error::Examples(format!("{}: {}", path.display(), message)))
+}
+
+// Retrieve the n-th (1-based) example of `day`
+pub fn get(dir: &Path, year: u16, day: u8, n: u8) -> Result<Example> {
+    let examples = load(dir, year, day)?;
+    let count = examples.len();
+    examples
+        .into_iter()
+        .nth((n as usize).wrapping_sub(1))
+        .ok_or_else(|| {
+            Error::Examples(format!(
+                "{} day #{} has no example #{} ({} given)",
+                year, day, n, count
+            ))
+        })
+}
+
+fn parse(content: &str) -> std::result::Result<Vec<Example>, String> {
+    let table: toml::Table = toml::from_str(content).map_err(|error| error.to_string())?;
+    let examples = match table.get("example") {
+        Some(toml::Value::Array(examples)) => examples,
+        Some(_) => return Err("`example` must be an array of tables".to_string()),
+        None => return Ok(Vec::new()),
+    };
+    examples
+        .iter()
+        .enumerate()
+        .map(|(i, example)| {
+            let invalid = |message: &str| format!("example #{}: {}", i + 1, message);
+            let input = match example.get("input") {
+                Some(toml::Value::String(input)) => input.clone(),
+                _ => return Err(invalid("expected an `input` string")),
+            };
+            let answer = |key: &str| match example.get(key) {
+                Some(toml::Value::String(answer)) => Ok(Some(answer.clone())),
+                Some(toml::Value::Integer(answer)) => Ok(Some(answer.to_string())),
+                Some(_) => Err(invalid(&format!(
+                    "`{}` must be a string or an integer",
+                    key
+                ))),
+                None => Ok(None),
+            };
+            Ok(Example {
+                input,
+                answers: [answer("part1")?, answer("part2")?],
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_examples() {
+        let content = "[[example]]\ninput = '''\n1 2\n'''\npart1 = \"3\"\n\n[[example]]\ninput = \"4\"\npart2 = 5\n";
+        let examples = parse(content).unwrap();
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].input, "1 2\n");
+        assert_eq!(examples[0].answer(1), Some("3"));
+        assert_eq!(examples[0].answer(2), None);
+        assert_eq!(examples[1].answer(2), Some("5"));
+
+        assert_eq!(parse("").unwrap(), Vec::new());
+        assert!(parse("[[example]]\npart1 = \"3\"\n").is_err());
+        assert!(parse("[[example]]\ninput = \"\"\npart1 = 1.5\n").is_err());
+    }
+}
@@ -0,0 +1,70 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::error::{Error, Result};
+
+/// Base URL of the Advent of Code website
+pub static AOC_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the session cookie
+pub static SESSION_ENV: &str = "AOC_SESSION";
+
+/// Identifies this tool to the website
+pub static USER_AGENT: &str = "github.com/yuyujijin/aoc";
+
+// The session cookie, if `given` or set in the environment
+pub fn session(given: Option<String>) -> Option<String> {
+    given.or_else(|| env::var(SESSION_ENV).ok())
+}
+
+/// Retrieves a puzzle input from somewhere
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String>;
+}
+
+/// Downloads inputs from the website, authenticated by a session cookie
+pub struct HttpFetcher {
+    session: String,
+}
+
+impl HttpFetcher {
+    pub fn new(session: String) -> HttpFetcher {
+        HttpFetcher { session }
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/{}/day/{}/input", AOC_URL, year, day);
+        ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|error| Error::Fetch(format!("{}: {}", url, error)))?
+            .into_string()
+            .map_err(|error| Error::Fetch(format!("{}: {}", url, error)))
+    }
+}
+
+/// Copies inputs from a local directory laid out as `<dir>/<year>/dayNN.txt`
+pub struct DirFetcher {
+    dir: PathBuf,
+}
+
+impl DirFetcher {
+    pub fn new(dir: PathBuf) -> DirFetcher {
+        DirFetcher { dir }
+    }
+}
+
+impl Fetcher for DirFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String> {
+        let path = self
+            .dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day));
+        fs::read_to_string(&path)
+            .map_err(|error| Error::Fetch(format!("{}: {}", path.display(), error)))
+    }
+}
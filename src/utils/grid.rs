@@ -0,0 +1,396 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::{Error, Result};
+
+// Sets of positions to highlight, each with the transform applied to its cells
+pub type Interests<F> = Vec<(HashSet<Point>, F)>;
+
+/// A position in a grid, `x` growing rightwards and `y` downwards
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Point {
+        Point { x, y }
+    }
+}
+
+/// A step towards one of the 8 neighbours, clockwise from `Up`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dir {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir {
+    /// The 4 orthogonal directions, clockwise from `Up`
+    pub const CARDINALS: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    /// Every direction, clockwise from `Up`
+    pub const ALL: [Dir; 8] = [
+        Dir::Up,
+        Dir::UpRight,
+        Dir::Right,
+        Dir::DownRight,
+        Dir::Down,
+        Dir::DownLeft,
+        Dir::Left,
+        Dir::UpLeft,
+    ];
+
+    // The `(dx, dy)` offset of a single step
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Dir::Up => (0, -1),
+            Dir::UpRight => (1, -1),
+            Dir::Right => (1, 0),
+            Dir::DownRight => (1, 1),
+            Dir::Down => (0, 1),
+            Dir::DownLeft => (-1, 1),
+            Dir::Left => (-1, 0),
+            Dir::UpLeft => (-1, -1),
+        }
+    }
+
+    // Rotates by `eighths` of a turn, clockwise
+    fn rotate(self, eighths: usize) -> Dir {
+        let index = Dir::ALL.iter().position(|dir| *dir == self).unwrap();
+        Dir::ALL[(index + eighths) % Dir::ALL.len()]
+    }
+
+    pub fn turn_right(self) -> Dir {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Dir {
+        self.rotate(6)
+    }
+
+    pub fn opposite(self) -> Dir {
+        self.rotate(4)
+    }
+}
+
+/// A rectangular grid of cells, stored row by row
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    // Builds a grid from its `cells`, given row by row
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "cells do not fill the grid");
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    // Parses one cell per char, one row per line, with `cell` converting each char.
+    // Every row must be as wide as the first one.
+    pub fn parse_with<F>(input: &str, mut cell: F) -> Result<Grid<T>>
+    where
+        F: FnMut(char) -> std::result::Result<T, String>,
+    {
+        let mut cells: Vec<T> = Vec::new();
+        let mut width: Option<usize> = None;
+        let mut height: usize = 0;
+        for (i, line) in input.lines().enumerate() {
+            let mut count: usize = 0;
+            for (j, c) in line.char_indices() {
+                let located = |message| Error::parse_token(i + 1, j + 1, c.len_utf8(), message);
+                cells.push(cell(c).map_err(located)?);
+                count += 1;
+            }
+            match width {
+                None => width = Some(count),
+                Some(width) if width != count => {
+                    return Err(Error::parse(
+                        i + 1,
+                        count.min(width) + 1,
+                        format!("expected a row of {} cells, found {}", width, count),
+                    ))
+                }
+                Some(_) => (),
+            }
+            height += 1;
+        }
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) {
+            Some(&self.cells[point.y * self.width + point.x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.y * self.width + point.x])
+        } else {
+            None
+        }
+    }
+
+    // Moves `point` by `(dx, dy)`, if it stays within the grid
+    pub fn offset(&self, point: Point, dx: isize, dy: isize) -> Option<Point> {
+        let x = point.x.checked_add_signed(dx)?;
+        let y = point.y.checked_add_signed(dy)?;
+        Some(Point::new(x, y)).filter(|point| self.contains(*point))
+    }
+
+    // Moves `point` one step towards `dir`, if it stays within the grid
+    pub fn step(&self, point: Point, dir: Dir) -> Option<Point> {
+        let (dx, dy) = dir.delta();
+        self.offset(point, dx, dy)
+    }
+
+    /// The orthogonal neighbours of `point` within the grid
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Dir::CARDINALS
+            .into_iter()
+            .filter_map(move |dir| self.step(point, dir))
+    }
+
+    /// The orthogonal and diagonal neighbours of `point` within the grid
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(point, dir))
+    }
+
+    /// Every position, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    // Position of the first cell, row by row, matching `predicate`
+    pub fn position<P>(&self, mut predicate: P) -> Option<Point>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.ray(Point::new(x, 0), Dir::Down)
+    }
+
+    // Cells from `start` (included) towards `dir`, up to the edge of the grid.
+    // Rows, columns and diagonals are all rays from an edge.
+    pub fn ray(&self, start: Point, dir: Dir) -> impl Iterator<Item = &T> {
+        let first = Some(start).filter(|point| self.contains(*point));
+        std::iter::successors(first, move |point| self.step(*point, dir))
+            .map(move |point| &self[point])
+    }
+
+    // Converts every cell, keeping the layout
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    // Draws the grid a row per line, the cells of the `interests` transformed by their function.
+    // A cell in several interests is transformed by the first one.
+    pub fn render<F>(&self, interests: Option<&Interests<F>>) -> String
+    where
+        T: fmt::Display,
+        F: Fn(&T) -> String,
+    {
+        let mut rendered = String::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let (point, cell) = (Point::new(x, y), &self[Point::new(x, y)]);
+                // Find if any of the interests contains the provided pos
+                let interest = interests
+                    .and_then(|interests| interests.iter().find(|(pos, _)| pos.contains(&point)));
+                match interest {
+                    // If so, apply transform method
+                    Some((_, f)) => rendered.push_str(&f(cell)),
+                    _ => rendered.push_str(&cell.to_string()),
+                }
+            }
+            rendered.push('\n');
+        }
+        rendered
+    }
+
+    pub fn pretty_print<F>(&self, interests: Option<&Interests<F>>)
+    where
+        T: fmt::Display,
+        F: Fn(&T) -> String,
+    {
+        print!("{}", self.render(interests));
+    }
+}
+
+impl Grid<char> {
+    pub fn parse(input: &str) -> Result<Grid<char>> {
+        Grid::parse_with(input, Ok)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).expect("point out of the grid")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point).expect("point out of the grid")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height {
+            for cell in self.row(y) {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = "abc\ndef\n";
+
+    #[test]
+    fn parse_and_display() {
+        let grid = Grid::parse(INPUT).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.to_string(), INPUT);
+
+        let empty = Grid::parse("").unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+    }
+
+    #[test]
+    fn reject_ragged_rows() {
+        assert_eq!(
+            Grid::parse("abc\nde\n"),
+            Err(Error::parse(2, 3, "expected a row of 3 cells, found 2"))
+        );
+        let digit = |c: char| c.to_digit(10).ok_or(format!("`{}` is not a digit", c));
+        assert_eq!(
+            Grid::parse_with("12\n3x\n", digit),
+            Err(Error::parse_token(2, 2, 1, "`x` is not a digit"))
+        );
+    }
+
+    #[test]
+    fn offsets_stay_in_bounds() {
+        let grid = Grid::parse(INPUT).unwrap();
+        let corner = Point::new(0, 0);
+        assert_eq!(grid.offset(corner, -1, 0), None);
+        assert_eq!(grid.offset(corner, 2, 1), Some(Point::new(2, 1)));
+        assert_eq!(grid.offset(corner, 3, 0), None);
+        assert_eq!(grid.step(corner, Dir::Up), None);
+        assert_eq!(grid.step(corner, Dir::DownRight), Some(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::parse(INPUT).unwrap();
+        let corner: Vec<Point> = grid.neighbours4(Point::new(0, 0)).collect();
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours4(Point::new(1, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn views() {
+        let grid = Grid::parse(INPUT).unwrap();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(
+            grid.ray(Point::new(0, 0), Dir::DownRight)
+                .collect::<String>(),
+            "ae"
+        );
+        assert_eq!(
+            grid.ray(Point::new(2, 0), Dir::DownLeft)
+                .collect::<String>(),
+            "ce"
+        );
+        assert_eq!(grid.ray(Point::new(3, 0), Dir::Left).count(), 0);
+        assert_eq!(grid.position(|c| *c == 'e'), Some(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn map_cells() {
+        let grid = Grid::parse("12\n34\n").unwrap();
+        let heights: Grid<u8> = grid.map(|c| c.to_digit(10).unwrap() as u8);
+        assert_eq!(heights[Point::new(1, 1)], 4);
+        assert_eq!(heights.iter().map(|(_, h)| *h as u32).sum::<u32>(), 10);
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Dir::Up.turn_right(), Dir::Right);
+        assert_eq!(Dir::Left.turn_right(), Dir::Up);
+        assert_eq!(Dir::Up.turn_left(), Dir::Left);
+        assert_eq!(Dir::DownRight.opposite(), Dir::UpLeft);
+    }
+
+    #[test]
+    fn render_interests() {
+        let grid = Grid::parse("ab\ncd\n").unwrap();
+        let upper = |c: &char| c.to_ascii_uppercase().to_string();
+        let interests = vec![(HashSet::from([Point::new(1, 0), Point::new(0, 1)]), upper)];
+        assert_eq!(grid.render(Some(&interests)), "aB\nCd\n");
+        assert_eq!(grid.render::<fn(&char) -> String>(None), grid.to_string());
+    }
+}
@@ -0,0 +1,53 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+/// Directory holding the inputs when nothing else is configured
+pub static DEFAULT_DIR: &str = "inputs";
+
+/// Environment variable overriding the inputs directory
+pub static DIR_ENV: &str = "AOC_INPUTS";
+
+/// Path standing for the standard input
+pub static STDIN: &str = "-";
+
+/// Resolves puzzle input files as `<dir>/<year>/day<NN>.txt`
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+impl Inputs {
+    // The directory is taken from `dir` if given, then from `AOC_INPUTS`, then defaults to `inputs`
+    pub fn new(dir: Option<PathBuf>) -> Inputs {
+        let dir = dir
+            .or_else(|| env::var_os(DIR_ENV).map(PathBuf::from))
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_DIR));
+        Inputs { dir }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    pub fn load(&self, year: u16, day: u8) -> Result<String> {
+        read(&self.path(year, day))
+    }
+}
+
+// Reads the whole file at `path`, or the standard input if `path` is `-`
+pub fn read(path: &Path) -> Result<String> {
+    let missing = |_| Error::MissingInput {
+        path: path.display().to_string(),
+    };
+    if path == Path::new(STDIN) {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map_err(missing)?;
+        return Ok(input);
+    }
+    fs::read_to_string(path).map_err(missing)
+}
@@ -0,0 +1,138 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use num_bigint::BigInt;
+use tiny_http::{Header, Request, Response, Server};
+
+/// What the stand-in remembers between submissions
+#[derive(Default)]
+struct State {
+    /// Set by a wrong answer, until `cool_down`
+    cooling: bool,
+    solved: bool,
+    submitted: Vec<String>,
+}
+
+/// A local stand-in for the website, answering submissions of a single part like it does
+pub struct MockServer {
+    server: Arc<Server>,
+    state: Arc<Mutex<State>>,
+}
+
+// Page wrapping `text` like the website does
+fn page(text: &str) -> String {
+    format!(
+        "<html><body><main><article><p>{}</p></article></main></body></html>",
+        text
+    )
+}
+
+// Decodes a `application/x-www-form-urlencoded` value
+fn decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded: Vec<u8> = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("");
+                match u8::from_str_radix(hex, 16) {
+                    Ok(byte) => {
+                        decoded.push(byte);
+                        i += 2;
+                    }
+                    Err(_) => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn reply(correct: &str, state: &Mutex<State>, mut request: Request) {
+    let mut body = String::new();
+    let _ = request.as_reader().read_to_string(&mut body);
+    let answer = body
+        .split('&')
+        .find_map(|field| field.strip_prefix("answer="))
+        .map(decode);
+    let authenticated = request.headers().iter().any(|header| {
+        header.field.equiv("Cookie") && header.value.as_str().starts_with("session=")
+    });
+
+    let (status, text) = match (answer, authenticated) {
+        (Some(answer), true) => {
+            let mut state = state.lock().unwrap();
+            state.submitted.push(answer.clone());
+            let text = if state.solved {
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            } else if state.cooling {
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 42s left to wait."
+            } else if answer == correct {
+                state.solved = true;
+                "That's the right answer!  You are one gold star closer to finding the Chief Historian."
+            } else {
+                state.cooling = true;
+                match (answer.parse::<BigInt>(), correct.parse::<BigInt>()) {
+                    (Ok(answer), Ok(correct)) if answer > correct => {
+                        "That's not the right answer; your answer is too high.  Please wait one minute before trying again."
+                    }
+                    (Ok(_), Ok(_)) => {
+                        "That's not the right answer; your answer is too low.  Please wait one minute before trying again."
+                    }
+                    _ => "That's not the right answer.  Please wait one minute before trying again.",
+                }
+            };
+            (200, page(text))
+        }
+        (None, _) => (400, "missing answer".to_string()),
+        (_, false) => (400, "missing session".to_string()),
+    };
+
+    let html = Header::from_bytes("Content-Type", "text/html").unwrap();
+    let response = Response::from_string(text)
+        .with_status_code(status)
+        .with_header(html);
+    let _ = request.respond(response);
+}
+
+impl MockServer {
+    // Starts answering on a free local port, `correct` being the right answer
+    pub fn start(correct: &str) -> MockServer {
+        let server = Arc::new(Server::http("127.0.0.1:0").unwrap());
+        let state: Arc<Mutex<State>> = Arc::default();
+
+        let (incoming, shared) = (Arc::clone(&server), Arc::clone(&state));
+        let correct = correct.to_string();
+        thread::spawn(move || {
+            for request in incoming.incoming_requests() {
+                reply(&correct, &shared, request);
+            }
+        });
+        MockServer { server, state }
+    }
+
+    // Base URL to submit to
+    pub fn url(&self) -> String {
+        format!("http://{}", self.server.server_addr())
+    }
+
+    // Ends the wait following a wrong answer
+    pub fn cool_down(&self) {
+        self.state.lock().unwrap().cooling = false;
+    }
+
+    // Every answer submitted so far
+    pub fn submitted(&self) -> Vec<String> {
+        self.state.lock().unwrap().submitted.clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+    }
+}
@@ -1,2 +1,24 @@
-pub mod matrix;
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod cancel;
+pub mod checked;
+pub mod cycle;
+pub mod diagnostic;
+pub mod examples;
+pub mod fetch;
+pub mod format;
+pub mod grid;
+pub mod input;
+#[cfg(test)]
+pub mod mock;
+pub mod parse;
+pub mod preflight;
+pub mod render;
 pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod store;
+pub mod submit;
+#[cfg(test)]
+pub mod testing;
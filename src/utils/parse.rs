@@ -0,0 +1,347 @@
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::utils::grid::Grid;
+
+// 1-based column of `token` in `line`.
+// `token` is expected to be a slice of `line`, otherwise the first column is reported.
+fn column(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    if offset <= line.len() {
+        offset + 1
+    } else {
+        1
+    }
+}
+
+/// A piece of the `index`-th (0-based) line of the input, locating the errors found in it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span<'a> {
+    line: &'a str,
+    index: usize,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    // The whole `index`-th (0-based) `line`
+    pub fn new(line: &'a str, index: usize) -> Span<'a> {
+        Span {
+            line,
+            index,
+            text: line,
+        }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    // The same span, without its surrounding whitespace
+    pub fn trim(&self) -> Span<'a> {
+        self.slice(self.text.trim())
+    }
+
+    // A part of the span: `text` must be a slice of it
+    fn slice(&self, text: &'a str) -> Span<'a> {
+        Span { text, ..*self }
+    }
+
+    // Error on the span, reported at its first column
+    pub fn error(&self, message: impl Into<String>) -> Error {
+        let column = column(self.line, self.text);
+        Error::parse_token(self.index + 1, column, self.text.len(), message)
+    }
+
+    // Error for a span that ended before the `expected` value
+    pub fn missing(&self, expected: &str) -> Error {
+        let end = column(self.line, self.text) + self.text.len();
+        Error::parse(self.index + 1, end, format!("expected {}", expected))
+    }
+}
+
+/// Parses a span into a `T`, or reports where it is malformed
+pub trait Parser<'a, T>: Fn(Span<'a>) -> Result<T> {}
+
+impl<'a, T, F> Parser<'a, T> for F where F: Fn(Span<'a>) -> Result<T> {}
+
+// A signed or unsigned integer, depending on `T`, surrounding whitespace aside
+pub fn int<'a, T: FromStr>() -> impl Parser<'a, T> {
+    |span: Span<'a>| {
+        let span = span.trim();
+        span.text
+            .parse::<T>()
+            .map_err(|_| span.error(format!("`{}` is not a valid number", span.text)))
+    }
+}
+
+// The value of a single digit
+pub fn digit(c: char) -> std::result::Result<u8, String> {
+    match c.to_digit(10) {
+        Some(digit) => Ok(digit as u8),
+        None => Err(format!("`{}` is not a digit", c)),
+    }
+}
+
+// A string of digits, such as `2333133121`, one value per digit
+pub fn digits<'a>() -> impl Parser<'a, Vec<u8>> {
+    |span: Span<'a>| {
+        span.text
+            .char_indices()
+            .map(|(i, c)| {
+                digit(c)
+                    .map_err(|message| span.slice(&span.text[i..i + c.len_utf8()]).error(message))
+            })
+            .collect()
+    }
+}
+
+// Items separated by `separator`, such as `75,47,61`
+pub fn list<'a, T>(separator: &'static str, item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |span: Span<'a>| {
+        span.text
+            .split(separator)
+            .map(|text| item(span.slice(text)))
+            .collect()
+    }
+}
+
+// Items separated by whitespace, such as `3   4`
+pub fn words<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |span: Span<'a>| {
+        span.text
+            .split_whitespace()
+            .map(|text| item(span.slice(text)))
+            .collect()
+    }
+}
+
+// Exactly `N` values from a parser of many, such as `array(words(int()))` for `3   4`
+pub fn array<'a, T, const N: usize>(items: impl Parser<'a, Vec<T>>) -> impl Parser<'a, [T; N]> {
+    move |span: Span<'a>| {
+        items(span)?.try_into().map_err(|items: Vec<T>| {
+            let found = format!("{} values, found {}", N, items.len());
+            match items.len() < N {
+                true => span.missing(&found),
+                false => span.error(format!("expected {}", found)),
+            }
+        })
+    }
+}
+
+// Two values around the first `separator`, such as `47|53`
+pub fn pair<'a, A, B>(
+    separator: &'static str,
+    left: impl Parser<'a, A>,
+    right: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |span: Span<'a>| match span.text.split_once(separator) {
+        Some((a, b)) => Ok((left(span.slice(a))?, right(span.slice(b))?)),
+        None => Err(span.missing(&format!("`{}`", separator))),
+    }
+}
+
+// A `key: values` record, the values being separated by whitespace, such as `190: 10 19`
+pub fn record<'a, K, V>(
+    key: impl Parser<'a, K>,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, (K, Vec<V>)> {
+    pair(":", key, words(value))
+}
+
+// Converts what `parser` returns with `f`
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |span: Span<'a>| parser(span).map(&f)
+}
+
+/// Consecutive lines of the input, starting at its `first`-th (0-based) line
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Section<'a> {
+    text: &'a str,
+    first: usize,
+}
+
+impl<'a> Section<'a> {
+    // The whole input
+    pub fn new(input: &'a str) -> Section<'a> {
+        Section {
+            text: input,
+            first: 0,
+        }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn spans(&self) -> impl Iterator<Item = Span<'a>> + 'a {
+        let first = self.first;
+        self.text
+            .lines()
+            .enumerate()
+            .map(move |(i, line)| Span::new(line, first + i))
+    }
+
+    // Parses each line with `parser`
+    pub fn lines<T>(&self, parser: impl Parser<'a, T>) -> Result<Vec<T>> {
+        self.spans().map(parser).collect()
+    }
+
+    // Parses the first line with `parser`
+    pub fn line<T>(&self, parser: impl Parser<'a, T>) -> Result<T> {
+        match self.spans().next() {
+            Some(span) => parser(span),
+            None => Err(Error::parse(self.first + 1, 1, "expected a line")),
+        }
+    }
+
+    // Parses one cell per char, one row per line, as `Grid::parse_with`
+    pub fn grid<T, F>(&self, cell: F) -> Result<Grid<T>>
+    where
+        F: FnMut(char) -> std::result::Result<T, String>,
+    {
+        Grid::parse_with(self.text, cell).map_err(|error| match error {
+            Error::Parse {
+                line,
+                column,
+                width,
+                message,
+            } => Error::parse_token(self.first + line, column, width, message),
+            error => error,
+        })
+    }
+
+    // The sections separated by blank lines
+    pub fn sections(&self) -> Vec<Section<'a>> {
+        let mut sections: Vec<Section<'a>> = Vec::new();
+        // Byte offset and index of the first line of the current section
+        let mut start: Option<(usize, usize)> = None;
+        let mut offset: usize = 0;
+        for (i, line) in self.text.split_inclusive('\n').enumerate() {
+            match (line.trim().is_empty(), start) {
+                (true, Some((from, first))) => {
+                    sections.push(Section {
+                        text: &self.text[from..offset],
+                        first: self.first + first,
+                    });
+                    start = None;
+                }
+                (false, None) => start = Some((offset, i)),
+                _ => (),
+            }
+            offset += line.len();
+        }
+        if let Some((from, first)) = start {
+            sections.push(Section {
+                text: &self.text[from..],
+                first: self.first + first,
+            });
+        }
+        sections
+    }
+
+    // Exactly `N` sections separated by blank lines
+    pub fn split<const N: usize>(&self) -> Result<[Section<'a>; N]> {
+        self.sections().try_into().map_err(|sections: Vec<_>| {
+            Error::parse(
+                self.first + self.text.lines().count() + 1,
+                1,
+                format!(
+                    "expected {} sections separated by a blank line, found {}",
+                    N,
+                    sections.len()
+                ),
+            )
+        })
+    }
+}
+
+// Parses each line of `input` with `parser`
+pub fn lines<'a, T>(input: &'a str, parser: impl Parser<'a, T>) -> Result<Vec<T>> {
+    Section::new(input).lines(parser)
+}
+
+// Exactly `N` sections of `input` separated by blank lines
+pub fn sections<const N: usize>(input: &str) -> Result<[Section<'_>; N]> {
+    Section::new(input).split()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combine_parsers() {
+        let span = |line| Span::new(line, 2);
+        assert_eq!(array(words(int::<i64>()))(span("3   -4")), Ok([3, -4]));
+        assert_eq!(
+            list(",", int::<u8>())(span("75,47,61")),
+            Ok(vec![75, 47, 61])
+        );
+        assert_eq!(pair("|", int::<u8>(), int())(span("47|53")), Ok((47, 53)));
+        assert_eq!(
+            record(int::<u64>(), int::<u64>())(span("190: 10 19")),
+            Ok((190, vec![10, 19]))
+        );
+        assert_eq!(digits()(span("2333")), Ok(vec![2, 3, 3, 3]));
+        assert_eq!(map(int::<u8>(), |x| x * 2)(span(" 21 ")), Ok(42));
+
+        // Errors are located on the line, whatever the nesting
+        let errors = [
+            (
+                list(",", int::<u8>())(span("75,4x,61")).unwrap_err(),
+                Error::parse_token(3, 4, 2, "`4x` is not a valid number"),
+            ),
+            (
+                record(int::<u64>(), int::<u64>())(span("190 10 19")).unwrap_err(),
+                Error::parse(3, 10, "expected `:`"),
+            ),
+            (
+                array::<_, 2>(words(int::<i64>()))(span("3")).unwrap_err(),
+                Error::parse(3, 2, "expected 2 values, found 1"),
+            ),
+            (
+                words(int::<u32>())(span("1 -2")).unwrap_err(),
+                Error::parse_token(3, 3, 2, "`-2` is not a valid number"),
+            ),
+            (
+                digits()(span("23x3")).unwrap_err(),
+                Error::parse_token(3, 3, 1, "`x` is not a digit"),
+            ),
+        ];
+        for (error, expected) in errors {
+            assert_eq!(error, expected);
+        }
+    }
+
+    #[test]
+    fn split_sections() {
+        let input = "47|53\n97|13\n\n\n75,47\n61\n\n";
+        let [rules, updates] = sections::<2>(input).unwrap();
+        assert_eq!(rules.text(), "47|53\n97|13\n");
+        assert_eq!(updates.text(), "75,47\n61\n");
+        assert_eq!(
+            updates.lines(list(",", int::<u8>())),
+            Ok(vec![vec![75, 47], vec![61]])
+        );
+        // Lines are numbered from the start of the input
+        assert_eq!(
+            updates.lines(int::<u8>()),
+            Err(Error::parse_token(5, 1, 5, "`75,47` is not a valid number"))
+        );
+        assert_eq!(
+            sections::<3>(input),
+            Err(Error::parse(
+                8,
+                1,
+                "expected 3 sections separated by a blank line, found 2"
+            ))
+        );
+
+        let grid = updates.grid(digit);
+        assert_eq!(grid, Err(Error::parse_token(5, 3, 1, "`,` is not a digit")));
+        assert_eq!(
+            Section::new("").line(digits()),
+            Err(Error::parse(1, 1, "expected a line"))
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::error::{Error, Result};
+use crate::utils::parse::Section;
+
+/// What an input must look like before being parsed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shape {
+    /// Any text
+    Any,
+    /// A single non-empty line
+    Line,
+    /// Rows of the same length
+    Grid,
+    /// As many rows as columns
+    SquareGrid,
+    /// This many sections separated by blank lines
+    Sections(usize),
+}
+
+impl Shape {
+    // Checks that the (normalized) `input` has this shape, pointing at where it does not
+    pub fn check(self, input: &str) -> Result<()> {
+        let lines: Vec<&str> = input.lines().collect();
+        match self {
+            Shape::Any => Ok(()),
+            Shape::Line => match lines.len() {
+                0 => Err(Error::parse(1, 1, "expected a line")),
+                1 => Ok(()),
+                n => Err(Error::parse(
+                    2,
+                    1,
+                    format!("expected a single line, found {}", n),
+                )),
+            },
+            Shape::Grid => rows(&lines).map(|_| ()),
+            Shape::SquareGrid => {
+                let width = rows(&lines)?;
+                match lines.len() {
+                    height if height == width => Ok(()),
+                    // Past the last expected row, or right after the last one
+                    height => Err(Error::parse(
+                        height.min(width) + 1,
+                        1,
+                        format!("expected a square grid of {} rows, found {}", width, height),
+                    )),
+                }
+            }
+            Shape::Sections(count) => match Section::new(input).sections().len() {
+                found if found == count => Ok(()),
+                found => Err(Error::parse(
+                    lines.len() + 1,
+                    1,
+                    format!(
+                        "expected {} sections separated by a blank line, found {}",
+                        count, found
+                    ),
+                )),
+            },
+        }
+    }
+}
+
+// Width of the rows, all as wide as the first one
+fn rows(lines: &[&str]) -> Result<usize> {
+    let width = match lines.first() {
+        Some(first) if !first.is_empty() => first.chars().count(),
+        _ => return Err(Error::parse(1, 1, "expected a grid")),
+    };
+    for (i, line) in lines.iter().enumerate() {
+        let count = line.chars().count();
+        if count != width {
+            let message = format!("expected a row of {} cells, found {}", width, count);
+            let column = line
+                .char_indices()
+                .nth(width)
+                .map_or(line.len(), |(j, _)| j);
+            return Err(Error::parse(i + 1, column + 1, message));
+        }
+    }
+    Ok(width)
+}
+
+// The input with `\n` line endings only, without trailing whitespace on each line nor trailing blank lines
+pub fn normalize(input: &str) -> String {
+    let lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+    let end = lines
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(0, |last| last + 1);
+    lines[..end]
+        .iter()
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+// Normalizes `input` then checks its `shape`
+pub fn prepare(input: &str, shape: Shape) -> Result<String> {
+    let input = normalize(input);
+    shape.check(&input)?;
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_inputs() {
+        assert_eq!(normalize("12 \r\n34\r\n\r\n\n"), "12\n34\n");
+        assert_eq!(normalize("\n12\n\n34"), "\n12\n\n34\n");
+        assert_eq!(normalize(" \n\n"), "");
+    }
+
+    #[test]
+    fn check_shapes() {
+        let cases = [
+            (Shape::Line, "2333133121\n", Ok(())),
+            (
+                Shape::Line,
+                "1\n2\n",
+                Err(Error::parse(2, 1, "expected a single line, found 2")),
+            ),
+            (Shape::Line, "", Err(Error::parse(1, 1, "expected a line"))),
+            (Shape::Grid, "ab\ncd\nef\n", Ok(())),
+            (
+                Shape::Grid,
+                "abc\nde\n",
+                Err(Error::parse(2, 3, "expected a row of 3 cells, found 2")),
+            ),
+            (
+                Shape::Grid,
+                "ab\ncde\n",
+                Err(Error::parse(2, 3, "expected a row of 2 cells, found 3")),
+            ),
+            (Shape::SquareGrid, "ab\ncd\n", Ok(())),
+            (
+                Shape::SquareGrid,
+                "ab\ncd\nef\n",
+                Err(Error::parse(
+                    3,
+                    1,
+                    "expected a square grid of 2 rows, found 3",
+                )),
+            ),
+            (Shape::Sections(2), "1|2\n\n1,2\n", Ok(())),
+            (
+                Shape::Sections(2),
+                "1|2\n1,2\n",
+                Err(Error::parse(
+                    3,
+                    1,
+                    "expected 2 sections separated by a blank line, found 1",
+                )),
+            ),
+        ];
+        for (shape, input, expected) in cases {
+            assert_eq!(shape.check(input), expected, "{:?} on {:?}", shape, input);
+        }
+        assert_eq!(
+            prepare("ab\r\ncd\r\n", Shape::SquareGrid),
+            Ok(String::from("ab\ncd\n"))
+        );
+    }
+}
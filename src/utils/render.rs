@@ -0,0 +1,271 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use colored::Colorize;
+
+use crate::error::{Error, Result};
+use crate::utils::grid::{Grid, Interests, Point};
+
+/// Color of a highlight overlay
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+}
+
+impl Color {
+    fn terminal(self) -> colored::Color {
+        match self {
+            Color::Red => colored::Color::Red,
+            Color::Green => colored::Color::Green,
+            Color::Yellow => colored::Color::Yellow,
+            Color::Blue => colored::Color::Blue,
+        }
+    }
+
+    fn rgb(self) -> [u8; 3] {
+        match self {
+            Color::Red => [220, 50, 47],
+            Color::Green => [133, 153, 0],
+            Color::Yellow => [181, 137, 0],
+            Color::Blue => [38, 139, 210],
+        }
+    }
+}
+
+// Pixel of a cell outside any overlay: dark for empty cells, light for the others
+fn shade(cell: char) -> [u8; 3] {
+    match cell {
+        '.' | ' ' => [16, 16, 16],
+        '#' => [200, 200, 200],
+        c if c.is_ascii_digit() => {
+            let level = 40 + c.to_digit(10).unwrap() as u8 * 20;
+            [level, level, level]
+        }
+        _ => [120, 120, 120],
+    }
+}
+
+/// Image formats frames are exported to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum Export {
+    Text,
+    Ppm,
+    Png,
+}
+
+impl Export {
+    fn extension(self) -> &'static str {
+        match self {
+            Export::Text => "txt",
+            Export::Ppm => "ppm",
+            Export::Png => "png",
+        }
+    }
+}
+
+/// The frames of an animation, drawn as they are played
+pub type Frames<'a> = Box<dyn Iterator<Item = Frame> + 'a>;
+
+/// A step of an animation: a grid of characters with colored overlays and a caption
+#[derive(Clone, Debug)]
+pub struct Frame {
+    grid: Grid<char>,
+    overlays: Vec<(HashSet<Point>, Color)>,
+    caption: String,
+}
+
+impl Frame {
+    pub fn new<T: fmt::Display>(grid: &Grid<T>) -> Frame {
+        Frame {
+            grid: grid.map(|cell| cell.to_string().chars().next().unwrap_or(' ')),
+            overlays: Vec::new(),
+            caption: String::new(),
+        }
+    }
+
+    // Highlights `points` in `color`, under the overlays added before it
+    pub fn highlight(mut self, points: impl IntoIterator<Item = Point>, color: Color) -> Frame {
+        self.overlays.push((points.into_iter().collect(), color));
+        self
+    }
+
+    // Replaces the character of `point`
+    pub fn mark(mut self, point: Point, cell: char) -> Frame {
+        if let Some(old) = self.grid.get_mut(point) {
+            *old = cell;
+        }
+        self
+    }
+
+    pub fn caption(mut self, caption: impl Into<String>) -> Frame {
+        self.caption = caption.into();
+        self
+    }
+
+    fn color(&self, point: Point) -> Option<Color> {
+        self.overlays
+            .iter()
+            .find(|(points, _)| points.contains(&point))
+            .map(|(_, color)| *color)
+    }
+
+    // The grid with its overlays in terminal colors, then the caption
+    pub fn to_terminal(&self) -> String {
+        let paint = |color: Color| {
+            move |cell: &char| cell.to_string().color(color.terminal()).bold().to_string()
+        };
+        let interests: Interests<_> = self
+            .overlays
+            .iter()
+            .map(|(points, color)| (points.clone(), paint(*color)))
+            .collect();
+        format!("{}{}\n", self.grid.render(Some(&interests)), self.caption)
+    }
+
+    // The grid as plain text, then the caption
+    pub fn to_text(&self) -> String {
+        format!("{}{}\n", self.grid, self.caption)
+    }
+
+    // RGB pixels of the frame, each cell drawn as a `scale` wide square
+    fn pixels(&self, scale: usize) -> (usize, usize, Vec<u8>) {
+        let (width, height) = (self.grid.width() * scale, self.grid.height() * scale);
+        let mut pixels: Vec<u8> = Vec::with_capacity(width * height * 3);
+        for y in 0..height {
+            for x in 0..width {
+                let point = Point::new(x / scale, y / scale);
+                let rgb = match self.color(point) {
+                    Some(color) => color.rgb(),
+                    None => shade(self.grid[point]),
+                };
+                pixels.extend(rgb);
+            }
+        }
+        (width, height, pixels)
+    }
+
+    // A binary PPM image of the frame
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let (width, height, pixels) = self.pixels(scale);
+        let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        image.extend(pixels);
+        image
+    }
+
+    // A PNG image of the frame
+    pub fn to_png(&self, scale: usize) -> Result<Vec<u8>> {
+        let (width, height, pixels) = self.pixels(scale);
+        let failed = |error: png::EncodingError| Error::Render(error.to_string());
+        let mut image: Vec<u8> = Vec::new();
+        let mut encoder = png::Encoder::new(&mut image, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(failed)?;
+        writer.write_image_data(&pixels).map_err(failed)?;
+        writer.finish().map_err(failed)?;
+        Ok(image)
+    }
+}
+
+// Plays `frames` in the terminal at `fps` frames per second, redrawing each over the previous one
+pub fn play(frames: Frames, fps: u32, out: &mut impl Write) -> io::Result<()> {
+    let delay = Duration::from_secs(1) / fps;
+    for (i, frame) in frames.enumerate() {
+        let start: Instant = Instant::now();
+        // Clear the screen, then draw from its top left corner
+        write!(out, "\x1b[2J\x1b[H{}", frame.to_terminal())?;
+        writeln!(out, "frame {}", i + 1)?;
+        out.flush()?;
+        thread::sleep(delay.saturating_sub(start.elapsed()));
+    }
+    Ok(())
+}
+
+// Writes each frame to `<dir>/<prefix>-NNNN.<ext>`, returning the paths written
+pub fn export(
+    frames: Frames,
+    dir: &Path,
+    prefix: &str,
+    format: Export,
+    scale: usize,
+) -> Result<Vec<PathBuf>> {
+    let failed =
+        |path: &Path, error: io::Error| Error::Render(format!("{}: {}", path.display(), error));
+    fs::create_dir_all(dir).map_err(|error| failed(dir, error))?;
+    frames
+        .enumerate()
+        .map(|(i, frame)| {
+            let path = dir.join(format!("{}-{:04}.{}", prefix, i + 1, format.extension()));
+            let content = match format {
+                Export::Text => frame.to_text().into_bytes(),
+                Export::Ppm => frame.to_ppm(scale),
+                Export::Png => frame.to_png(scale)?,
+            };
+            fs::write(&path, content).map_err(|error| failed(&path, error))?;
+            Ok(path)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::TempDir;
+
+    fn frame() -> Frame {
+        let grid = Grid::parse("..#\n.^.\n").unwrap();
+        Frame::new(&grid)
+            .highlight([Point::new(1, 1)], Color::Red)
+            .highlight([Point::new(1, 1), Point::new(0, 0)], Color::Green)
+            .caption("step 1")
+    }
+
+    #[test]
+    fn draw_frames() {
+        let frame = frame();
+        assert_eq!(frame.to_text(), "..#\n.^.\nstep 1\n");
+        assert_eq!(frame.color(Point::new(1, 1)), Some(Color::Red));
+        assert_eq!(frame.color(Point::new(0, 0)), Some(Color::Green));
+        assert_eq!(frame.color(Point::new(2, 0)), None);
+
+        let ppm = frame.to_ppm(2);
+        let header = b"P6\n6 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 6 * 4 * 3);
+        // The top left pixel is in the green overlay
+        assert_eq!(&ppm[header.len()..header.len() + 3], &Color::Green.rgb());
+
+        let png = frame.to_png(2).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+
+    #[test]
+    fn export_frames() {
+        let dir = TempDir::new("render");
+        let frames = vec![frame(), frame().caption("step 2")];
+        let paths = export(
+            Box::new(frames.into_iter()),
+            &dir,
+            "day06-part1",
+            Export::Text,
+            1,
+        )
+        .unwrap();
+        assert_eq!(
+            paths,
+            [
+                dir.join("day06-part1-0001.txt"),
+                dir.join("day06-part1-0002.txt")
+            ]
+        );
+        assert_eq!(fs::read_to_string(&paths[1]).unwrap(), "..#\n.^.\nstep 2\n");
+    }
+}
@@ -1,17 +1,392 @@
-use std::{
-    sync::mpsc::{self, Receiver, Sender},
-    thread,
-};
-
-pub fn parallelize<F>(functions: Vec<F>) -> i64
-where
-    F: Fn() -> i64 + Send + 'static,
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+use crate::utils::{cancel, checked};
+
+/// A fixed number of worker threads, mapping items in parallel and reducing their results.
+/// Items are split into chunks taken in turn by the workers, so an idle one picks up the next.
+/// The workers report overflows as the calling thread does.
+/// They share its deadline too, and stop taking chunks once it is past:
+/// the whole mapping then fails with `Error::Cancelled`, rather than returning partial results.
+#[derive(Clone, Copy, Debug)]
+pub struct Executor {
+    workers: usize,
+    chunk: Option<usize>,
+}
+
+impl Default for Executor {
+    // As many workers as the machine can run at once
+    fn default() -> Self {
+        Executor::new(thread::available_parallelism().map_or(1, |n| n.get()))
+    }
+}
+
+impl Executor {
+    // An executor of `workers` threads (at least one)
+    pub fn new(workers: usize) -> Self {
+        Executor {
+            workers: workers.max(1),
+            chunk: None,
+        }
+    }
+
+    // Sets the number of items taken at once, by default a few chunks per worker
+    pub fn with_chunk(self, chunk: usize) -> Self {
+        Executor {
+            chunk: Some(chunk.max(1)),
+            ..self
+        }
+    }
+
+    fn chunk(&self, items: usize) -> usize {
+        self.chunk
+            .unwrap_or_else(|| items.div_ceil(self.workers * 4))
+            .max(1)
+    }
+
+    // Runs `task` on every chunk of `items`, returning the results in the chunks' order.
+    // Fails if the deadline passed before every chunk was done.
+    fn chunks<T, R, C>(&self, items: &[T], task: C) -> Result<Vec<R>>
+    where
+        T: Sync,
+        R: Send,
+        C: Fn(&[T]) -> R + Sync,
+    {
+        let chunks: Vec<&[T]> = items.chunks(self.chunk(items.len())).collect();
+        let next = AtomicUsize::new(0);
+        let (tx, rx) = mpsc::channel::<(usize, R)>();
+        let (deadline, checks) = (cancel::deadline(), checked::enabled());
+
+        thread::scope(|scope| {
+            for _ in 0..self.workers.min(chunks.len()) {
+                let tx = tx.clone();
+                let (chunks, next, task) = (&chunks, &next, &task);
+                scope.spawn(move || {
+                    checked::with_checks(checks, || {
+                        cancel::with_deadline(deadline, || loop {
+                            let index = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(chunk) = chunks.get(index) else {
+                                break;
+                            };
+                            if cancel::cancelled() {
+                                break;
+                            }
+                            tx.send((index, task(chunk))).unwrap();
+                        })
+                    })
+                });
+            }
+        });
+        drop(tx);
+
+        let mut results: Vec<(usize, R)> = rx.into_iter().collect();
+        if results.len() < chunks.len() {
+            return Err(Error::Cancelled);
+        }
+        results.sort_by_key(|(index, _)| *index);
+        Ok(results.into_iter().map(|(_, result)| result).collect())
+    }
+
+    // Maps every item then combines the results with `reduce`, `None` without any item.
+    // Results are combined in the order of `items`, so `reduce` only needs to be associative.
+    pub fn map_reduce<T, R, M, F>(&self, items: &[T], map: M, reduce: F) -> Result<Option<R>>
+    where
+        T: Sync,
+        R: Send,
+        M: Fn(&T) -> R + Sync,
+        F: Fn(R, R) -> R + Sync,
+    {
+        Ok(self
+            .chunks(items, |chunk| chunk.iter().map(&map).reduce(&reduce))?
+            .into_iter()
+            .flatten()
+            .reduce(&reduce))
+    }
+
+    // Maps every item, keeping their order
+    pub fn map<T, R, M>(&self, items: &[T], map: M) -> Result<Vec<R>>
+    where
+        T: Sync,
+        R: Send,
+        M: Fn(&T) -> R + Sync,
+    {
+        Ok(self
+            .chunks(items, |chunk| chunk.iter().map(&map).collect::<Vec<R>>())?
+            .into_iter()
+            .flatten()
+            .collect())
+    }
+}
+
+/// Why a job has no result
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Failure {
+    /// The job panicked, with this message
+    Panicked(String),
+    /// The job was still running at the end of its time budget
+    TimedOut(Duration),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Panicked(message) => write!(f, "panicked: {}", message),
+            Failure::TimedOut(timeout) => write!(f, "timed out after {:?}", timeout),
+        }
+    }
+}
+
+/// Outcome of a job
+pub type Outcome<R> = std::result::Result<R, Failure>;
+
+// Message of a panic, from its payload
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    }
+}
+
+// Runs `task` on each job, at most `workers` at once (at least one), each on a thread of its own.
+// Outcomes are handed to `report` on the calling thread in the order of `jobs`, each as soon as
+// the previous ones are. A panicking task only fails its own job.
+// With a `timeout`, a job still running after it fails as timed out, and its thread is left
+// behind: its deadline is set for `cancel::check`, so that it can stop on its own.
+pub fn run_ordered<J, R, F, G>(
+    jobs: &[J],
+    workers: usize,
+    timeout: Option<Duration>,
+    task: F,
+    mut report: G,
+) where
+    J: Clone + Send + 'static,
+    R: Send + 'static,
+    F: Fn(&J) -> R + Send + Sync + 'static,
+    G: FnMut(&J, Outcome<R>),
 {
-    let (tx, rx): (Sender<i64>, Receiver<i64>) = mpsc::channel();
-    functions.into_iter().for_each(|f| {
-        let tx_2 = tx.clone();
-        thread::spawn(move || tx_2.send(f()).unwrap());
-    });
-    drop(tx);
-    rx.iter().reduce(|prev, next| prev + next).unwrap()
+    let task = Arc::new(task);
+    // Index, run time and outcome of the finished jobs
+    let (tx, rx) = mpsc::channel::<(usize, Duration, thread::Result<R>)>();
+    // Start of the running jobs
+    let mut running: BTreeMap<usize, Instant> = BTreeMap::new();
+    // Holds the outcomes done ahead of their turn
+    let mut pending: BTreeMap<usize, Outcome<R>> = BTreeMap::new();
+    let (mut started, mut reported) = (0, 0);
+
+    while reported < jobs.len() {
+        while running.len() < workers.max(1) && started < jobs.len() {
+            let (index, job, task, tx) = (
+                started,
+                jobs[started].clone(),
+                Arc::clone(&task),
+                tx.clone(),
+            );
+            running.insert(index, Instant::now());
+            thread::spawn(move || {
+                // The deadline counts from the job's own start, as its run time does.
+                // A deadline too far to be represented is as good as none.
+                let start = Instant::now();
+                let deadline = timeout.and_then(|timeout| start.checked_add(timeout));
+                let outcome = cancel::with_deadline(deadline, || {
+                    panic::catch_unwind(AssertUnwindSafe(|| task(&job)))
+                });
+                // Nobody listens anymore once every job is reported
+                let _ = tx.send((index, start.elapsed(), outcome));
+            });
+            started += 1;
+        }
+
+        // Wait for a job to finish, or for the oldest one to run out of time
+        let next = timeout.and_then(|timeout| running.values().min()?.checked_add(timeout));
+        let received = match next {
+            Some(next) => rx
+                .recv_timeout(next.saturating_duration_since(Instant::now()))
+                .ok(),
+            None => rx.recv().ok(),
+        };
+        // Along with the jobs that finished while `report` was busy, judged on their own run time
+        for (index, elapsed, outcome) in received.into_iter().chain(rx.try_iter()) {
+            // Skip the jobs already reported as timed out
+            if running.remove(&index).is_none() {
+                continue;
+            }
+            let outcome = match timeout {
+                Some(timeout) if elapsed >= timeout => Err(Failure::TimedOut(timeout)),
+                _ => outcome.map_err(|payload| Failure::Panicked(panic_message(payload))),
+            };
+            pending.insert(index, outcome);
+        }
+        // Only the jobs still running can be out of time
+        if let Some(timeout) = timeout {
+            running.retain(|&index, start| match start.elapsed() >= timeout {
+                true => {
+                    pending.insert(index, Err(Failure::TimedOut(timeout)));
+                    false
+                }
+                false => true,
+            });
+        }
+
+        while let Some(outcome) = pending.remove(&reported) {
+            report(&jobs[reported], outcome);
+            reported += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use super::*;
+
+    #[test]
+    fn map_reduce_in_order() {
+        let items: Vec<u32> = (1..=100).collect();
+        for executor in [
+            Executor::new(1),
+            Executor::new(3).with_chunk(7),
+            Executor::default(),
+        ] {
+            let sum = executor.map_reduce(&items, |&x| x as u64, |a, b| a + b);
+            assert_eq!(sum, Ok(Some(5050)));
+            // Concatenation is not commutative, the order must be kept
+            let text = executor.map_reduce(&items[..12], |x| x.to_string(), |a, b| a + &b);
+            assert_eq!(text, Ok(Some(String::from("123456789101112"))));
+            assert_eq!(
+                executor.map(&items, |&x| x * 2),
+                Ok((2..=200).step_by(2).collect::<Vec<u32>>())
+            );
+            assert_eq!(
+                executor.map_reduce(&[] as &[u32], |&x| x, |a, b| a + b),
+                Ok(None)
+            );
+        }
+    }
+
+    #[test]
+    fn cancel_mapping() {
+        let items: Vec<u32> = (1..=100).collect();
+        let result = cancel::with_deadline(Some(Instant::now()), || {
+            Executor::new(2).map_reduce(&items, |&x| x, |a, b| a + b)
+        });
+        assert_eq!(result, Err(Error::Cancelled));
+    }
+
+    #[test]
+    fn share_overflow_checks() {
+        // The additions run on the workers
+        let items: Vec<u64> = vec![1, u64::MAX];
+        let add = || {
+            Executor::new(2)
+                .with_chunk(1)
+                .map(&items, |&x| checked::add(x, 1))
+        };
+        assert_eq!(add(), Ok(vec![Ok(2), Ok(0)]));
+        let added = checked::with_checks(true, add);
+        assert!(matches!(
+            added.as_deref(),
+            Ok([Ok(2), Err(Error::Overflow(_))])
+        ));
+    }
+
+    #[test]
+    fn ordered_outcomes() {
+        let jobs: Vec<u64> = (0..20).collect();
+        let mut outcomes: Vec<(u64, Outcome<u64>)> = Vec::new();
+        run_ordered(
+            &jobs,
+            4,
+            None,
+            |&job| {
+                // Later jobs finish first
+                thread::sleep(std::time::Duration::from_millis(20 - job));
+                if job == 7 {
+                    panic!("job {} failed", job);
+                }
+                job * 2
+            },
+            |&job, outcome| outcomes.push((job, outcome)),
+        );
+
+        assert_eq!(outcomes.len(), 20);
+        for (i, (job, outcome)) in outcomes.into_iter().enumerate() {
+            assert_eq!(job, i as u64);
+            match job {
+                7 => assert_eq!(outcome, Err(Failure::Panicked("job 7 failed".to_string()))),
+                _ => assert_eq!(outcome, Ok(job * 2)),
+            }
+        }
+    }
+
+    #[test]
+    fn slow_reports() {
+        let jobs: Vec<u64> = vec![0, 1, 2];
+        let timeout = Duration::from_millis(50);
+        let mut outcomes: Vec<Outcome<u64>> = Vec::new();
+        run_ordered(
+            &jobs,
+            2,
+            Some(timeout),
+            |&job| job * 2,
+            |_, outcome| {
+                // Reporting takes longer than any job is allowed to run
+                thread::sleep(timeout * 2);
+                outcomes.push(outcome);
+            },
+        );
+        assert_eq!(outcomes, [Ok(0), Ok(2), Ok(4)]);
+    }
+
+    #[test]
+    fn time_out_jobs() {
+        let jobs: Vec<u64> = vec![0, 1, 2, 3];
+        let timeout = Duration::from_millis(200);
+        let mut outcomes: Vec<Outcome<u64>> = Vec::new();
+        // Lets the job ignoring its deadline end with the test
+        static STOP: AtomicBool = AtomicBool::new(false);
+        run_ordered(
+            &jobs,
+            2,
+            Some(timeout),
+            |&job| match job {
+                // Only ends once the run is over
+                1 => {
+                    while !STOP.load(Ordering::Relaxed) {
+                        thread::sleep(Duration::from_millis(10));
+                    }
+                    0
+                }
+                // Gives up by itself
+                2 => loop {
+                    if cancel::check().is_err() {
+                        return 0;
+                    }
+                    let _ = Executor::new(2).map(&[1, 2, 3], |&x| x);
+                },
+                _ => job * 2,
+            },
+            |_, outcome| outcomes.push(outcome),
+        );
+        STOP.store(true, Ordering::Relaxed);
+        assert_eq!(
+            outcomes,
+            [
+                Ok(0),
+                Err(Failure::TimedOut(timeout)),
+                Err(Failure::TimedOut(timeout)),
+                Ok(6)
+            ]
+        );
+    }
 }
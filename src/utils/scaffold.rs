@@ -0,0 +1,231 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+use crate::utils::examples;
+use crate::utils::input::Inputs;
+
+/// Directory of the year modules, from the root of this crate's sources
+pub static YEARS_DIR: &str = "src/years";
+
+static TEMPLATE: &str = include_str!("../years/day.template.rs");
+
+// Opening of the registered days list, in `years/y<year>/mod.rs`
+static REGISTER: &str = "register! {\n";
+
+// Opening of the registered years list, in `years/mod.rs`
+static YEARS: &str = "years! {\n";
+
+// Examples of a new day, to be filled in from the statement
+static EXAMPLES: &str = "\
+# Each example gives the answers of the parts it applies to, as `part1 = \"...\"`
+[[example]]
+input = '''
+'''
+";
+
+/// Files created for a new day
+pub struct Created {
+    pub module: PathBuf,
+    /// The empty input and examples files, unless they already existed
+    pub files: Vec<PathBuf>,
+}
+
+// Fills the template in for `day`
+pub fn render(year: u16, day: u8, title: &str) -> String {
+    TEMPLATE
+        .replace("DayN", &format!("Day{:02}", day))
+        .replace("day: 0,", &format!("day: {},", day))
+        .replace("title: \"\",", &format!("title: {:?},", title))
+        .replace("year: 2024,", &format!("year: {},", year))
+}
+
+// Adds `entry` to the list opened by `list` in `source`, keeping it ordered
+fn insert(source: &str, list: &str, entry: String) -> Result<String> {
+    let invalid = || Error::Scaffold(format!("no `{}` list", list.trim_end()));
+    let start = source.find(list).ok_or_else(invalid)? + list.len();
+    let end = start + source[start..].find("}\n").ok_or_else(invalid)?;
+
+    let mut entries: Vec<String> = source[start..end]
+        .lines()
+        .map(|line| format!("{}\n", line))
+        .collect();
+    if entries.contains(&entry) {
+        return Err(Error::Scaffold(format!(
+            "`{}` is already registered",
+            entry.trim()
+        )));
+    }
+    entries.push(entry);
+    entries.sort();
+
+    Ok(format!(
+        "{}{}{}",
+        &source[..start],
+        entries.concat(),
+        &source[end..]
+    ))
+}
+
+// Adds `day` to the `register!` list of a year's `mod_rs`
+pub fn register_day(mod_rs: &str, day: u8) -> Result<String> {
+    insert(
+        mod_rs,
+        REGISTER,
+        format!("    day{:02}::Day{:02},\n", day, day),
+    )
+}
+
+// Adds `year` to the `years!` list of the years' `mod_rs`
+pub fn register_year(mod_rs: &str, year: u16) -> Result<String> {
+    insert(mod_rs, YEARS, format!("    y{},\n", year))
+}
+
+// Creates the module of `day` in the `year` module of `dir` and registers it, along with
+// an empty input and examples. A missing year module is created and registered as well.
+// Nothing is overwritten: an existing day module is an error, existing files are kept.
+// Every registration is prepared before writing anything, and undone if a write fails.
+pub fn create(
+    dir: &Path,
+    inputs: &Inputs,
+    examples_dir: &Path,
+    year: u16,
+    day: u8,
+    title: &str,
+) -> Result<Created> {
+    let failed = |path: &Path, error: std::io::Error| {
+        Error::Scaffold(format!("{}: {}", path.display(), error))
+    };
+    let read = |path: &Path| fs::read_to_string(path).map_err(|error| failed(path, error));
+
+    let year_dir = dir.join(format!("y{}", year));
+    let module = year_dir.join(format!("day{:02}.rs", day));
+    if module.exists() {
+        return Err(Error::Scaffold(format!(
+            "{} already exists",
+            module.display()
+        )));
+    }
+
+    // New content of each file, along with its previous one if any
+    let year_mod_rs = year_dir.join("mod.rs");
+    let mut writes: Vec<(PathBuf, String, Option<String>)> = Vec::new();
+    match year_mod_rs.exists() {
+        true => {
+            let source = read(&year_mod_rs)?;
+            writes.push((year_mod_rs, register_day(&source, day)?, Some(source)));
+        }
+        false => {
+            let years_mod_rs = dir.join("mod.rs");
+            let source = read(&years_mod_rs)?;
+            writes.push((years_mod_rs, register_year(&source, year)?, Some(source)));
+            let source = format!("{}}}\n", REGISTER);
+            writes.push((year_mod_rs, register_day(&source, day)?, None));
+        }
+    }
+    writes.push((module.clone(), render(year, day, title), None));
+
+    let created_dir = !year_dir.exists();
+    if created_dir {
+        fs::create_dir_all(&year_dir).map_err(|error| failed(&year_dir, error))?;
+    }
+    for (i, (path, content, _)) in writes.iter().enumerate() {
+        if let Err(error) = fs::write(path, content) {
+            // Put back what was there before, as far as possible
+            for (path, _, previous) in &writes[..i] {
+                let _ = match previous {
+                    Some(previous) => fs::write(path, previous),
+                    None => fs::remove_file(path),
+                };
+            }
+            if created_dir {
+                let _ = fs::remove_dir(&year_dir);
+            }
+            return Err(failed(path, error));
+        }
+    }
+
+    let mut files: Vec<PathBuf> = Vec::new();
+    let empty = [
+        (inputs.path(year, day), ""),
+        (examples::path(examples_dir, year, day), EXAMPLES),
+    ];
+    for (path, content) in empty {
+        if path.exists() {
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|error| failed(parent, error))?;
+        }
+        fs::write(&path, content).map_err(|error| failed(&path, error))?;
+        files.push(path);
+    }
+
+    Ok(Created { module, files })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::TempDir;
+
+    static YEAR_MOD_RS: &str = "register! {\n    day01::Day01,\n    day03::Day03,\n}\n";
+
+    static MOD_RS: &str = "years! {\n    y2024,\n}\n\npub fn latest() {}\n";
+
+    #[test]
+    fn render_template() {
+        let module = render(2024, 12, "Garden Groups");
+        assert!(module.contains("pub struct Day12;"));
+        assert!(module.contains("impl Solution for Day12"));
+        assert!(module.contains("day: 12,"));
+        assert!(module.contains("title: \"Garden Groups\","));
+        assert!(!module.contains("DayN"));
+    }
+
+    #[test]
+    fn register_in_order() {
+        let expected = "register! {\n    day01::Day01,\n    day02::Day02,\n    day03::Day03,\n}\n";
+        assert_eq!(register_day(YEAR_MOD_RS, 2).unwrap(), expected);
+        assert!(register_day(YEAR_MOD_RS, 3).is_err());
+        assert!(register_day(MOD_RS, 2).is_err());
+
+        let expected = "years! {\n    y2023,\n    y2024,\n}\n\npub fn latest() {}\n";
+        assert_eq!(register_year(MOD_RS, 2023).unwrap(), expected);
+    }
+
+    #[test]
+    fn refuse_to_overwrite() {
+        let root = TempDir::new("scaffold");
+        let dir = root.join("years");
+        fs::create_dir_all(dir.join("y2024")).unwrap();
+        fs::write(dir.join("mod.rs"), MOD_RS).unwrap();
+        fs::write(dir.join("y2024").join("mod.rs"), YEAR_MOD_RS).unwrap();
+        let inputs = Inputs::new(Some(root.join("inputs")));
+        let examples_dir = root.join("examples");
+
+        let created = create(&dir, &inputs, &examples_dir, 2024, 2, "").unwrap();
+        assert_eq!(created.module, dir.join("y2024").join("day02.rs"));
+        assert_eq!(created.files.len(), 2);
+        let registry = fs::read_to_string(dir.join("y2024").join("mod.rs")).unwrap();
+        assert!(registry.contains("day02::Day02"));
+        assert_eq!(inputs.load(2024, 2).unwrap(), "");
+        let example = examples::get(&examples_dir, 2024, 2, 1).unwrap();
+        assert_eq!((example.input.as_str(), example.answer(1)), ("", None));
+
+        assert!(create(&dir, &inputs, &examples_dir, 2024, 2, "").is_err());
+
+        // A new year gets its own module
+        create(&dir, &inputs, &examples_dir, 2023, 1, "").unwrap();
+        let registry = fs::read_to_string(dir.join("y2023").join("mod.rs")).unwrap();
+        assert_eq!(registry, "register! {\n    day01::Day01,\n}\n");
+        let years = fs::read_to_string(dir.join("mod.rs")).unwrap();
+        assert!(years.contains("    y2023,\n    y2024,\n"));
+
+        // Nothing is left behind when a year cannot be registered
+        fs::write(dir.join("mod.rs"), "pub fn latest() {}\n").unwrap();
+        assert!(create(&dir, &inputs, &examples_dir, 2022, 1, "").is_err());
+        assert!(!dir.join("y2022").exists());
+        assert!(!inputs.path(2022, 1).exists());
+    }
+}
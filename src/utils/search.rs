@@ -0,0 +1,354 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// Rebuilds the path from the start to `end`, given the predecessor of each reached node
+fn path_to<N>(end: N, parents: &HashMap<N, N>) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+{
+    let mut path = vec![end];
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+// Every node reachable from `start`, `start` included, in breadth-first order
+pub fn reachable<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen: HashSet<N> = HashSet::from([start.clone()]);
+    let mut order: Vec<N> = vec![start];
+    let mut i = 0;
+    while let Some(node) = order.get(i) {
+        let next: Vec<N> = neighbours(node)
+            .into_iter()
+            .filter(|next| seen.insert(next.clone()))
+            .collect();
+        order.extend(next);
+        i += 1;
+    }
+    order
+}
+
+// A shortest path (in steps) from `start` to a node satisfying `goal`, both included
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut seen: HashSet<N> = HashSet::from([start.clone()]);
+    let mut queue: VecDeque<N> = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            return Some(path_to(node, &parents));
+        }
+        for next in neighbours(&node) {
+            if seen.insert(next.clone()) {
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+// A path from `start` to a node satisfying `goal`, found depth first (not the shortest one)
+pub fn dfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut seen: HashSet<N> = HashSet::new();
+    let mut stack: Vec<(N, Option<N>)> = vec![(start, None)];
+    while let Some((node, parent)) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        if let Some(parent) = parent {
+            parents.insert(node.clone(), parent);
+        }
+        if goal(&node) {
+            return Some(path_to(node, &parents));
+        }
+        // Pushed in reverse, so that the first neighbour is explored first
+        let next: Vec<N> = neighbours(&node).into_iter().collect();
+        for next in next.into_iter().rev() {
+            if !seen.contains(&next) {
+                stack.push((next, Some(node.clone())));
+            }
+        }
+    }
+    None
+}
+
+// A cheapest path from `start` to a node satisfying `goal`, with its cost.
+// `neighbours` gives each neighbour with the cost of the step to it, which must not be negative.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), goal)
+}
+
+// A cheapest path from `start` to a node satisfying `goal`, with its cost, as `dijkstra`.
+// `heuristic` estimates the cost from a node to the goal, and must never overestimate it.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let zero = C::default();
+    // Queued nodes are referred to by their index, so that they need no ordering
+    let mut nodes: Vec<N> = vec![start.clone()];
+    let mut costs: HashMap<N, C> = HashMap::from([(start.clone(), zero)]);
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), zero, 0))]);
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        let node = nodes[index].clone();
+        // Already reached more cheaply
+        if costs.get(&node).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        if goal(&node) {
+            return Some((path_to(node, &parents), cost));
+        }
+        for (next, step) in neighbours(&node) {
+            let total = cost + step;
+            if costs.get(&next).is_none_or(|&best| total < best) {
+                costs.insert(next.clone(), total);
+                parents.insert(next.clone(), node.clone());
+                queue.push(Reverse((total + heuristic(&next), total, nodes.len())));
+                nodes.push(next);
+            }
+        }
+    }
+    None
+}
+
+// Number of distinct paths from `start` to the nodes satisfying `goal`, a path ending on its
+// first such node. The graph must not have cycles; the paths from each node are counted once.
+pub fn count_paths<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> usize
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    fn count<N, I>(
+        node: &N,
+        neighbours: &mut impl FnMut(&N) -> I,
+        goal: &mut impl FnMut(&N) -> bool,
+        memo: &mut HashMap<N, usize>,
+    ) -> usize
+    where
+        N: Clone + Eq + Hash,
+        I: IntoIterator<Item = N>,
+    {
+        if let Some(&paths) = memo.get(node) {
+            return paths;
+        }
+        let paths = match goal(node) {
+            true => 1,
+            false => {
+                let next: Vec<N> = neighbours(node).into_iter().collect();
+                next.iter()
+                    .map(|next| count(next, neighbours, goal, memo))
+                    .sum()
+            }
+        };
+        memo.insert(node.clone(), paths);
+        paths
+    }
+
+    count(&start, &mut neighbours, &mut goal, &mut HashMap::new())
+}
+
+// Orders the nodes reachable from `nodes` so that each one comes before its neighbours,
+// ties kept in the order they are found. A graph with a cycle gives one of its nodes instead.
+pub fn topological_sort<N, I>(nodes: &[N], mut neighbours: impl FnMut(&N) -> I) -> Result<Vec<N>, N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    // Every reachable node, with its neighbours and the number of edges coming in
+    let mut found: Vec<N> = Vec::new();
+    let mut edges: HashMap<N, Vec<N>> = HashMap::new();
+    let mut incoming: HashMap<N, usize> = HashMap::new();
+    for node in nodes {
+        for node in reachable(node.clone(), &mut neighbours) {
+            if edges.contains_key(&node) {
+                continue;
+            }
+            let next: Vec<N> = neighbours(&node).into_iter().collect();
+            for next in &next {
+                *incoming.entry(next.clone()).or_default() += 1;
+            }
+            edges.insert(node.clone(), next);
+            found.push(node);
+        }
+    }
+
+    let mut queue: VecDeque<N> = found
+        .iter()
+        .filter(|node| !incoming.contains_key(*node))
+        .cloned()
+        .collect();
+    let mut sorted: Vec<N> = Vec::with_capacity(found.len());
+    while let Some(node) = queue.pop_front() {
+        for next in &edges[&node] {
+            let count = incoming.get_mut(next).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                queue.push_back(next.clone());
+            }
+        }
+        sorted.push(node);
+    }
+
+    match sorted.len() == found.len() {
+        true => Ok(sorted),
+        // The nodes left are on a cycle or after one, find one on it
+        false => {
+            let sorted: HashSet<N> = sorted.into_iter().collect();
+            let mut on_cycle = |node: &N| {
+                reachable(node.clone(), &mut neighbours)
+                    .iter()
+                    .any(|other| edges[other].contains(node))
+            };
+            Err(found
+                .into_iter()
+                .find(|node| !sorted.contains(node) && on_cycle(node))
+                .expect("a node on a cycle"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::grid::{Grid, Point};
+
+    // From `S` to `E` around the walls
+    static MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E
+";
+
+    fn maze() -> (Grid<char>, Point, Point) {
+        let grid = Grid::parse(MAZE).unwrap();
+        let start = grid.position(|c| *c == 'S').unwrap();
+        let end = grid.position(|c| *c == 'E').unwrap();
+        (grid, start, end)
+    }
+
+    #[test]
+    fn unweighted_searches() {
+        let (grid, start, end) = maze();
+        let open = |point: &Point| {
+            grid.neighbours4(*point)
+                .filter(|next| grid[*next] != '#')
+                .collect::<Vec<Point>>()
+        };
+
+        let path = bfs(start, open, |point| *point == end).unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!((path[0], path[15]), (start, end));
+        let path = dfs(start, open, |point| *point == end).unwrap();
+        assert_eq!((path[0], *path.last().unwrap()), (start, end));
+        // Each step of the path is to a neighbour
+        assert!(path
+            .windows(2)
+            .all(|step| open(&step[0]).contains(&step[1])));
+
+        assert_eq!(bfs(start, open, |point| grid[*point] == '?'), None);
+        assert_eq!(reachable(start, open).len(), 27);
+        assert_eq!(reachable(start, open)[0], start);
+    }
+
+    #[test]
+    fn weighted_searches() {
+        let (grid, start, end) = maze();
+        // Going down costs 2, other steps 1
+        let open = |point: &Point| {
+            let point = *point;
+            grid.neighbours4(point)
+                .filter(|next| grid[*next] != '#')
+                .map(move |next| (next, if next.y > point.y { 2 } else { 1 }))
+                .collect::<Vec<(Point, u32)>>()
+        };
+        let (path, cost) = dijkstra(start, open, |point| *point == end).unwrap();
+        assert_eq!((path[0], *path.last().unwrap(), cost), (start, end, 21));
+
+        let distance = |point: &Point| (end.x.abs_diff(point.x) + end.y.abs_diff(point.y)) as u32;
+        let (path, cost) = astar(start, open, distance, |point| *point == end).unwrap();
+        assert_eq!((path.len(), cost), (16, 21));
+    }
+
+    #[test]
+    fn count_paths_in_dag() {
+        // From the top left corner to the bottom right one, only going right or down
+        let grid: Grid<u8> = Grid::new(4, 4, vec![0; 16]);
+        let paths = count_paths(
+            Point::new(0, 0),
+            |point| {
+                [grid.offset(*point, 1, 0), grid.offset(*point, 0, 1)]
+                    .into_iter()
+                    .flatten()
+            },
+            |point| *point == Point::new(3, 3),
+        );
+        assert_eq!(paths, 20);
+    }
+
+    #[test]
+    fn sort_topologically() {
+        let edges: HashMap<u8, Vec<u8>> = HashMap::from([
+            (5, vec![11]),
+            (7, vec![11, 8]),
+            (3, vec![8, 10]),
+            (11, vec![2, 9, 10]),
+            (8, vec![9]),
+        ]);
+        let neighbours = |node: &u8| edges.get(node).cloned().unwrap_or_default();
+        let sorted = topological_sort(&[5, 7, 3], neighbours).unwrap();
+        assert_eq!(sorted, [5, 7, 3, 11, 8, 2, 10, 9]);
+
+        let cycle: HashMap<u8, Vec<u8>> = HashMap::from([(1, vec![2]), (2, vec![3]), (3, vec![2])]);
+        let neighbours = |node: &u8| cycle.get(node).cloned().unwrap_or_default();
+        assert_eq!(topological_sort(&[1], neighbours), Err(2));
+    }
+}
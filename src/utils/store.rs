@@ -0,0 +1,95 @@
+use std::fs;
+use std::io;
+use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::{Error, Result};
+
+/// A value kept in a TOML file, starting from its default while the file does not exist.
+/// Its errors are reported with `error`, prefixed by the path of the file.
+pub struct Store<T> {
+    path: PathBuf,
+    error: fn(String) -> Error,
+    value: T,
+}
+
+impl<T: Serialize + DeserializeOwned + Default> Store<T> {
+    // Reads the value stored at `path`, if any
+    pub fn load(path: PathBuf, error: fn(String) -> Error) -> Result<Store<T>> {
+        let mut store = Store {
+            path,
+            error,
+            value: T::default(),
+        };
+        match fs::read_to_string(&store.path) {
+            Ok(content) => {
+                store.value = toml::from_str(&content).map_err(|e| store.error(e.to_string()))?
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => (),
+            Err(error) => return Err(store.error(error.to_string())),
+        }
+        Ok(store)
+    }
+
+    // Writes the value, creating the directory of the file if needed
+    pub fn save(&self) -> Result<()> {
+        let content = toml::to_string(&self.value).map_err(|e| self.error(e.to_string()))?;
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|e| self.error(e.to_string()))?;
+        }
+        fs::write(&self.path, content).map_err(|e| self.error(e.to_string()))
+    }
+}
+
+impl<T> Store<T> {
+    // An error about the content of the file
+    pub fn error(&self, message: String) -> Error {
+        (self.error)(format!("{}: {}", self.path.display(), message))
+    }
+}
+
+impl<T> Deref for Store<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Store<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::TempDir;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn load_then_save() {
+        let dir = TempDir::new("store");
+        let path = dir.join("2024.toml");
+
+        let mut store: Store<BTreeMap<String, u32>> =
+            Store::load(path.clone(), Error::Answers).unwrap();
+        assert!(store.is_empty());
+        store.insert("day06".to_string(), 41);
+        store.save().unwrap();
+        let store: Store<BTreeMap<String, u32>> =
+            Store::load(path.clone(), Error::Answers).unwrap();
+        assert_eq!(store.get("day06"), Some(&41));
+
+        // Errors name the file
+        fs::write(&path, "day06 = \"41\"").unwrap();
+        let error = Store::<BTreeMap<String, u32>>::load(path.clone(), Error::Answers);
+        assert!(
+            matches!(error, Err(Error::Answers(message)) if message.starts_with(&path.display().to_string()))
+        );
+    }
+}
@@ -0,0 +1,380 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+
+use num_bigint::BigInt;
+
+use crate::error::{Error, Result};
+use crate::utils::answers::{day_key, part_key};
+use crate::utils::fetch::USER_AGENT;
+use crate::utils::store::Store;
+
+/// How the website took a submitted answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Response {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without telling which way
+    Wrong,
+    /// Too soon after the previous submission, retry after `wait`
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part was already solved, or is not unlocked yet
+    WrongLevel,
+}
+
+impl Response {
+    // Reads the response from the page returned by the website
+    pub fn parse(page: &str) -> Result<Response> {
+        if page.contains("That's the right answer") {
+            Ok(Response::Correct)
+        } else if page.contains("your answer is too high") {
+            Ok(Response::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Ok(Response::TooLow)
+        } else if page.contains("That's not the right answer") {
+            Ok(Response::Wrong)
+        } else if page.contains("You gave an answer too recently") {
+            Ok(Response::RateLimited {
+                wait: parse_wait(page).unwrap_or(Duration::from_secs(60)),
+            })
+        } else if page.contains("You don't seem to be solving the right level") {
+            Ok(Response::WrongLevel)
+        } else {
+            Err(Error::Submit(
+                "unexpected response from the website".to_string(),
+            ))
+        }
+    }
+}
+
+// Reads the wait of a `You have 1m 4s left to wait` notice
+fn parse_wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+    page[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let (value, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Sends an answer to be checked
+pub trait Submitter {
+    fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Response>;
+}
+
+/// Posts answers to the website, authenticated by a session cookie
+pub struct HttpSubmitter {
+    url: String,
+    session: String,
+}
+
+impl HttpSubmitter {
+    // Posts to the website at `url`, `AOC_URL` or one laid out the same such as a local stand-in
+    pub fn new(url: String, session: String) -> HttpSubmitter {
+        HttpSubmitter { url, session }
+    }
+}
+
+impl Submitter for HttpSubmitter {
+    fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Response> {
+        let url = format!("{}/{}/day/{}/answer", self.url, year, day);
+        let page = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|error| Error::Submit(format!("{}: {}", url, error)))?
+            .into_string()
+            .map_err(|error| Error::Submit(format!("{}: {}", url, error)))?;
+        Response::parse(&page)
+    }
+}
+
+/// What previous submissions of a part taught
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Attempts {
+    /// The answer found correct
+    pub correct: Option<String>,
+    /// The highest answer found too low
+    pub low: Option<BigInt>,
+    /// The lowest answer found too high
+    pub high: Option<BigInt>,
+    /// Every other answer found wrong
+    pub wrong: Vec<String>,
+}
+
+impl Attempts {
+    // Why `answer` is known to be wrong without submitting it, if it is
+    pub fn refuse(&self, answer: &str) -> Option<String> {
+        if let Some(correct) = &self.correct {
+            return Some(match correct == answer {
+                true => "already found correct".to_string(),
+                false => format!("the correct answer is {}", correct),
+            });
+        }
+        if self.wrong.iter().any(|wrong| wrong == answer) {
+            return Some("already found wrong".to_string());
+        }
+        let value: BigInt = answer.parse().ok()?;
+        match (&self.low, &self.high) {
+            (Some(low), _) if value <= *low => Some(format!("{} was already too low", low)),
+            (_, Some(high)) if value >= *high => Some(format!("{} was already too high", high)),
+            _ => None,
+        }
+    }
+
+    // Learns from the `response` to the submission of `answer`
+    pub fn learn(&mut self, answer: &str, response: &Response) {
+        let value = answer.parse::<BigInt>().ok();
+        match (response, value) {
+            (Response::Correct, _) => self.correct = Some(answer.to_string()),
+            (Response::TooLow, Some(value)) => {
+                self.low = self.low.take().max(Some(value));
+            }
+            (Response::TooHigh, Some(value)) => {
+                self.high = Some(match self.high.take() {
+                    Some(high) => high.min(value),
+                    None => value,
+                });
+            }
+            (Response::TooLow | Response::TooHigh | Response::Wrong, _) => {
+                self.wrong.push(answer.to_string())
+            }
+            (Response::RateLimited { .. } | Response::WrongLevel, _) => {}
+        }
+    }
+}
+
+/// Submissions of a year, stored in `<dir>/<year>.submissions.toml` as:
+///
+/// ```toml
+/// [day06.part1]
+/// low = "30"
+/// high = "500"
+/// wrong = ["42"]
+/// ```
+pub struct Submissions {
+    table: Store<toml::Table>,
+    parts: BTreeMap<(u8, u8), Attempts>,
+}
+
+// Reads the attempts of a part from its table
+fn attempts_from(table: &toml::Table) -> std::result::Result<Attempts, String> {
+    let text = |key: &str| match table.get(key) {
+        Some(toml::Value::String(value)) => Ok(Some(value.clone())),
+        Some(_) => Err(format!("`{}` must be a string", key)),
+        None => Ok(None),
+    };
+    let number = |key: &str| match text(key)? {
+        Some(value) => value
+            .parse::<BigInt>()
+            .map(Some)
+            .map_err(|_| format!("`{}` must be a number", key)),
+        None => Ok(None),
+    };
+    let wrong = match table.get("wrong") {
+        Some(toml::Value::Array(values)) => values
+            .iter()
+            .map(|value| match value {
+                toml::Value::String(value) => Ok(value.clone()),
+                _ => Err("`wrong` must be an array of strings".to_string()),
+            })
+            .collect::<std::result::Result<_, _>>()?,
+        Some(_) => return Err("`wrong` must be an array of strings".to_string()),
+        None => Vec::new(),
+    };
+    Ok(Attempts {
+        correct: text("correct")?,
+        low: number("low")?,
+        high: number("high")?,
+        wrong,
+    })
+}
+
+fn attempts_to(attempts: &Attempts) -> toml::Table {
+    let mut table = toml::Table::new();
+    if let Some(correct) = &attempts.correct {
+        table.insert("correct".to_string(), correct.clone().into());
+    }
+    if let Some(low) = &attempts.low {
+        table.insert("low".to_string(), low.to_string().into());
+    }
+    if let Some(high) = &attempts.high {
+        table.insert("high".to_string(), high.to_string().into());
+    }
+    if !attempts.wrong.is_empty() {
+        table.insert("wrong".to_string(), attempts.wrong.clone().into());
+    }
+    table
+}
+
+impl Submissions {
+    // Loads the submissions of `year`, starting empty if none were made yet
+    pub fn load(dir: &Path, year: u16) -> Result<Submissions> {
+        let table: Store<toml::Table> = Store::load(
+            dir.join(format!("{}.submissions.toml", year)),
+            Error::Submit,
+        )?;
+        let invalid = |message: String| table.error(message);
+
+        let mut parts = BTreeMap::new();
+        for day in 1..=25 {
+            if table.get(&day_key(day)).is_some_and(|day| !day.is_table()) {
+                return Err(invalid(format!("{} must be a table", day_key(day))));
+            }
+            for part in 1..=2 {
+                let attempts = table
+                    .get(&day_key(day))
+                    .and_then(|day| day.get(part_key(part)));
+                match attempts {
+                    Some(toml::Value::Table(attempts)) => {
+                        parts.insert((day, part), attempts_from(attempts).map_err(invalid)?);
+                    }
+                    Some(_) => {
+                        return Err(invalid(format!(
+                            "{}.{} must be a table",
+                            day_key(day),
+                            part_key(part)
+                        )))
+                    }
+                    None => {}
+                }
+            }
+        }
+        Ok(Submissions { table, parts })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Attempts {
+        self.parts.get(&(day, part)).cloned().unwrap_or_default()
+    }
+
+    // Learns from the `response` to the submission of `answer` for `part` of `day`
+    pub fn learn(&mut self, day: u8, part: u8, answer: &str, response: &Response) {
+        let attempts = self.parts.entry((day, part)).or_default();
+        attempts.learn(answer, response);
+        // Days were checked to be tables on load
+        let day = self
+            .table
+            .entry(day_key(day))
+            .or_insert_with(|| toml::Table::new().into());
+        if let toml::Value::Table(day) = day {
+            day.insert(part_key(part), attempts_to(attempts).into());
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        self.table.save()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::mock::MockServer;
+    use crate::utils::testing::TempDir;
+
+    #[test]
+    fn parse_responses() {
+        let page = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+        let cases = [
+            (
+                "That's the right answer! You are one gold star closer.",
+                Response::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.",
+                Response::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Response::TooLow,
+            ),
+            (
+                "That's not the right answer. If you're stuck...",
+                Response::Wrong,
+            ),
+            (
+                "You gave an answer too recently. You have 1m 4s left to wait.",
+                Response::RateLimited {
+                    wait: Duration::from_secs(64),
+                },
+            ),
+            (
+                "You don't seem to be solving the right level.",
+                Response::WrongLevel,
+            ),
+        ];
+        for (text, response) in cases {
+            assert_eq!(Response::parse(&page(text)).unwrap(), response);
+        }
+        assert!(Response::parse("<html></html>").is_err());
+    }
+
+    #[test]
+    fn refuse_known_answers() {
+        let mut attempts = Attempts::default();
+        attempts.learn("100", &Response::TooLow);
+        attempts.learn("500", &Response::TooHigh);
+        attempts.learn("300", &Response::TooHigh);
+        attempts.learn("250", &Response::Wrong);
+        assert!(attempts.refuse("99").is_some());
+        assert!(attempts.refuse("100").is_some());
+        assert!(attempts.refuse("400").is_some());
+        assert!(attempts.refuse("250").is_some());
+        assert_eq!(attempts.refuse("200"), None);
+        assert_eq!(attempts.refuse("text"), None);
+
+        attempts.learn("200", &Response::Correct);
+        assert!(attempts.refuse("200").is_some());
+        assert!(attempts.refuse("201").is_some());
+    }
+
+    #[test]
+    fn submit_to_mock_server() {
+        let server = MockServer::start("41");
+        let submitter = HttpSubmitter::new(server.url(), "session".to_string());
+        let dir = TempDir::new("submit");
+
+        let mut submissions = Submissions::load(&dir, 2024).unwrap();
+        let response = submitter.submit(2024, 6, 1, "40").unwrap();
+        assert_eq!(response, Response::TooLow);
+        submissions.learn(6, 1, "40", &response);
+        // A wrong answer makes the next one wait
+        assert!(matches!(
+            submitter.submit(2024, 6, 1, "41").unwrap(),
+            Response::RateLimited { .. }
+        ));
+        server.cool_down();
+        let response = submitter.submit(2024, 6, 1, "45").unwrap();
+        assert_eq!(response, Response::TooHigh);
+        submissions.learn(6, 1, "45", &response);
+        submissions.save().unwrap();
+
+        let mut submissions = Submissions::load(&dir, 2024).unwrap();
+        assert!(submissions.get(6, 1).refuse("46").is_some());
+        assert_eq!(submissions.get(6, 1).refuse("41"), None);
+
+        server.cool_down();
+        let response = submitter.submit(2024, 6, 1, "41").unwrap();
+        assert_eq!(response, Response::Correct);
+        submissions.learn(6, 1, "41", &response);
+        assert!(submissions.get(6, 1).refuse("41").is_some());
+        assert_eq!(
+            submitter.submit(2024, 6, 1, "41").unwrap(),
+            Response::WrongLevel
+        );
+        assert_eq!(server.submitted(), ["40", "41", "45", "41", "41"]);
+    }
+}
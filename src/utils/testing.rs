@@ -0,0 +1,36 @@
+use std::env;
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// A directory of the system's temporary one, removed along with its content once dropped,
+/// even when the test using it fails
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    // A directory named after `name` and the process, starting without any leftover of a previous run.
+    // It is not created, for the tests to check how it gets created.
+    pub fn new(name: &str) -> TempDir {
+        let path = env::temp_dir().join(format!("aoc-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&path);
+        TempDir { path }
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        // Nothing to remove if the test never created it
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::solution::{Info, Solution};
+use crate::utils::parse::{self, Span};
+
+/// The parsed puzzle input
+pub struct Input {
+    lines: Vec<String>,
+}
+
+fn part1(input: &Input) -> Result<Answer> {
+    for _line in &input.lines {
+        // Do something...
+    }
+    Err(Error::Unsolved)
+}
+
+fn part2(input: &Input) -> Result<Answer> {
+    for _line in &input.lines {
+        // Do something...
+    }
+    Err(Error::Unsolved)
+}
+
+fn parse(input: &str) -> Result<Input> {
+    // See `utils::parse` for numbers, lists, records, sections, grids...
+    Ok(Input {
+        lines: parse::lines(input, |line: Span| Ok(line.text().to_string()))?,
+    })
+}
+
+pub struct DayN;
+
+impl Solution for DayN {
+    type Input = Input;
+
+    fn info(&self) -> Info {
+        Info {
+            year: 2024,
+            day: 0,
+            title: "",
+        }
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,75 @@
+use crate::error::{Error, Result};
+use crate::solution::Solver;
+
+// Declares every day module of a year and registers its solution.
+// Adding a day only requires a new line in the year's `register!` call.
+macro_rules! register {
+    ($($module:ident::$solution:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Every registered solution of the year, ordered by day
+        pub static SOLUTIONS: &[&dyn $crate::solution::Solver] = &[$(&$module::$solution),*];
+    };
+}
+
+// Declares every year module, each holding a `register!` call.
+// Adding a year only requires a new line in the `years!` call below.
+macro_rules! years {
+    ($($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// The solutions of every registered year, oldest first
+        static YEARS: &[&[&dyn Solver]] = &[$($module::SOLUTIONS),*];
+    };
+}
+
+years! {
+    y2024,
+}
+
+/// Every registered solution, ordered by year then day
+pub fn solutions() -> impl Iterator<Item = &'static dyn Solver> {
+    YEARS.iter().flat_map(|solutions| solutions.iter().copied())
+}
+
+/// The most recent year with a registered day
+pub fn latest() -> u16 {
+    solutions()
+        .map(|solver| solver.info().year)
+        .max()
+        .expect("no registered day")
+}
+
+/// Finds the solution registered for `day` of `year`
+pub fn find(year: u16, day: u8) -> Result<&'static dyn Solver> {
+    solutions()
+        .find(|solver| solver.info().year == year && solver.info().day == day)
+        .ok_or(Error::UnknownDay { year, day })
+}
+
+/// Every registered day of `year`, in order
+pub fn registered(year: u16) -> Vec<u8> {
+    solutions()
+        .map(|solver| solver.info())
+        .filter(|info| info.year == year)
+        .map(|info| info.day)
+        .collect()
+}
+
+#[cfg(test)]
+mod examples {
+    use std::path::Path;
+
+    use crate::utils::examples::{self, DEFAULT_DIR};
+
+    // Solves `part` of the n-th example of a day, expecting the answer given with it
+    fn check(year: u16, day: u8, n: u8, part: u8) {
+        let example = examples::get(Path::new(DEFAULT_DIR), year, day, n).unwrap();
+        let solver = super::find(year, day).unwrap();
+        let result = solver.solve(part, &example.input).unwrap();
+        assert_eq!(Some(result.to_string().as_str()), example.answer(part));
+    }
+
+    // One test per example answer, see `build.rs`
+    include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use std::iter::zip;
+
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::{Info, Solution};
+use crate::utils::parse::{self, array, int, words};
+
+/// Both location lists, side by side in the input
+pub struct Lists {
+    left: Vec<i64>,
+    right: Vec<i64>,
+}
+
+fn part1(lists: &Lists) -> Result<Answer> {
+    let mut distances: i64 = 0;
+    let mut left_list: Vec<i64> = lists.left.clone();
+    let mut right_list: Vec<i64> = lists.right.clone();
+    // Sort both lists
+    left_list.sort();
+    right_list.sort();
+    // Iterate through, calculting distances
+    for (a, b) in zip(left_list, right_list) {
+        distances += (a - b).abs();
+    }
+    Ok(distances.into())
+}
+
+fn part2(lists: &Lists) -> Result<Answer> {
+    let mut distances: i64 = 0;
+    let mut right_map: HashMap<i64, i64> = HashMap::new();
+    // Increase map[key]
+    for right in &lists.right {
+        increase_hash_map(&mut right_map, *right);
+    }
+    // Iterate through left and multiply by occurences in right if exists in right
+    for key in &lists.left {
+        if let Some(occurences) = right_map.get(key) {
+            distances += key * occurences;
+        }
+    }
+    Ok(distances.into())
+}
+
+// Retrieve columns
+fn parse(input: &str) -> Result<Lists> {
+    let mut lists = Lists {
+        left: Vec::new(),
+        right: Vec::new(),
+    };
+    // Both columns of each line
+    for [left, right] in parse::lines(input, array(words(int::<i64>())))? {
+        lists.left.push(left);
+        lists.right.push(right);
+    }
+    Ok(lists)
+}
+
+fn increase_hash_map(map: &mut HashMap<i64, i64>, key: i64) {
+    let count = map.entry(key).or_insert(0);
+    *count += 1;
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Lists;
+
+    fn info(&self) -> Info {
+        Info {
+            year: 2024,
+            day: 1,
+            title: "Historian Hysteria",
+        }
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
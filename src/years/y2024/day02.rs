@@ -0,0 +1,128 @@
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::{Info, Solution};
+use crate::utils::parse::{self, int, words};
+
+fn vec_without(vec: &[i64], idx: usize) -> Vec<i64> {
+    if idx > vec.len() {
+        return Vec::<i64>::new();
+    }
+    [&vec[0..idx], &vec[idx + 1..]].concat().to_vec()
+}
+
+/// The levels of a report
+type Report = Vec<i64>;
+
+fn part1(reports: &[Report]) -> Result<Answer> {
+    let mut counter: i64 = 0;
+    // Iterate through the reports
+    for report in reports {
+        if is_safe(report, None) {
+            counter += 1;
+        }
+    }
+    Ok(counter.into())
+}
+
+fn part2(reports: &[Report]) -> Result<Answer> {
+    let mut counter: i64 = 0;
+    for report in reports {
+        // * Check if safe dampenered
+        if is_safe_dampenered(report)
+            // * If not, check if without first element is safe
+            || is_safe(&vec_without(report, 0), None)
+            // * If not, check without second element is safe
+            || is_safe(&vec_without(report, 1), None)
+        {
+            counter += 1;
+        }
+    }
+    Ok(counter.into())
+}
+
+// Parse each line as a report
+fn parse(input: &str) -> Result<Vec<Report>> {
+    parse::lines(input, words(int()))
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Report>;
+
+    fn info(&self) -> Info {
+        Info {
+            year: 2024,
+            day: 2,
+            title: "Red-Nosed Reports",
+        }
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
+
+fn is_safe(elems: &[i64], compare: Option<fn(i64, i64) -> bool>) -> bool {
+    let n: usize = elems.len();
+    static DELTA_MAX: i64 = 3;
+
+    if n < 2 {
+        return true;
+    }
+
+    let [first, second] = [elems.first().unwrap(), elems.get(1).unwrap()];
+
+    let compare: fn(i64, i64) -> bool = match compare {
+        Some(f) => f,
+        _ => {
+            if first < second {
+                |a: i64, b: i64| a < b && (a - b).abs() <= DELTA_MAX
+            } else {
+                |a: i64, b: i64| a > b && (a - b).abs() <= DELTA_MAX
+            }
+        }
+    };
+
+    if !compare(*first, *second) {
+        return false;
+    }
+
+    is_safe(&elems[1..], Some(compare))
+}
+
+fn is_safe_dampenered(elems: &[i64]) -> bool {
+    let size: usize = elems.len();
+    static DELTA_MAX: i64 = 3;
+
+    if size < 2 {
+        return true;
+    }
+
+    let [first, second] = [elems.first().unwrap(), elems.get(1).unwrap()];
+
+    let compare: fn(i64, i64) -> bool = if first < second {
+        |a: i64, b: i64| a < b && (a - b).abs() <= DELTA_MAX
+    } else {
+        |a: i64, b: i64| a > b && (a - b).abs() <= DELTA_MAX
+    };
+
+    let mut i: usize = 0;
+    while i < size - 1 {
+        let [n, m]: [i64; 2] = [*elems.get(i).unwrap(), *elems.get(i + 1).unwrap()];
+        if !compare(n, m) {
+            return is_safe(&vec_without(elems, i), None)
+                || is_safe(&vec_without(elems, i + 1), None);
+        }
+        i += 1;
+    }
+    true
+}
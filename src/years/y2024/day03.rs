@@ -0,0 +1,80 @@
+use regex::Regex;
+
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::{Info, Solution};
+
+/// An instruction found in the corrupted memory
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(i64, i64),
+    Do,
+    Dont,
+}
+
+fn part1(instructions: &[Instruction]) -> Result<Answer> {
+    Ok(instructions
+        .iter()
+        // Compute every multiplication
+        .map(|instruction| match instruction {
+            Instruction::Mul(left, right) => left * right,
+            _ => 0,
+        })
+        .sum::<i64>()
+        .into())
+}
+
+fn part2(instructions: &[Instruction]) -> Result<Answer> {
+    let mut enabled: bool = true;
+    let mut sum: i64 = 0;
+    for instruction in instructions {
+        match instruction {
+            Instruction::Mul(left, right) if enabled => sum += left * right,
+            Instruction::Mul(_, _) => (),
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+        }
+    }
+    Ok(sum.into())
+}
+
+// Retrieve the valid instructions, anything else is corrupted memory
+fn parse(input: &str) -> Result<Vec<Instruction>> {
+    let re: Regex = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\)").unwrap();
+    Ok(re
+        // Iterate throuh captures
+        .captures_iter(input)
+        .map(|m| match &m[0] {
+            "do()" => Instruction::Do,
+            "don't()" => Instruction::Dont,
+            // At most 3 digits, always fits
+            _ => Instruction::Mul(m[1].parse().unwrap(), m[2].parse().unwrap()),
+        })
+        .collect())
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Instruction>;
+
+    fn info(&self) -> Info {
+        Info {
+            year: 2024,
+            day: 3,
+            title: "Mull It Over",
+        }
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -0,0 +1,116 @@
+use regex::Regex;
+
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::{Info, Solution};
+use crate::utils::grid::{Dir, Grid, Point};
+use crate::utils::preflight::Shape;
+
+fn part1(grid: &Grid<char>) -> Result<Answer> {
+    Ok(compute_1(grid).into())
+}
+
+fn part2(grid: &Grid<char>) -> Result<Answer> {
+    Ok(compute_2(grid).into())
+}
+
+fn parse(input: &str) -> Result<Grid<char>> {
+    Grid::parse(input)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+
+    fn info(&self) -> Info {
+        Info {
+            year: 2024,
+            day: 4,
+            title: "Ceres Search",
+        }
+    }
+
+    fn shape(&self) -> Shape {
+        Shape::Grid
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
+
+fn count_words(str: &str) -> usize {
+    // We use two regex because we allow overlapping XMASAMX
+    let xmas: Regex = Regex::new(r"XMAS").unwrap();
+    let samx: Regex = Regex::new(r"SAMX").unwrap();
+    xmas.captures_iter(str).count() + samx.captures_iter(str).count()
+}
+
+// Every row, column and diagonal of the grid, as strings
+fn lines(grid: &Grid<char>) -> Vec<String> {
+    let (width, height): (usize, usize) = (grid.width(), grid.height());
+    let top = (0..width).map(|x| Point::new(x, 0));
+    let left = (1..height).map(|y| Point::new(0, y));
+    let right = (1..height).map(|y| Point::new(width.saturating_sub(1), y));
+
+    let rows = (0..height).map(|y| grid.row(y).iter().collect());
+    let columns = (0..width).map(|x| grid.column(x).collect());
+    // Negative slop (\)
+    let negatives = top
+        .clone()
+        .chain(left)
+        .map(|start| grid.ray(start, Dir::DownRight).collect());
+    // Positive slop (/)
+    let positives = top
+        .chain(right)
+        .map(|start| grid.ray(start, Dir::DownLeft).collect());
+    rows.chain(columns)
+        .chain(negatives)
+        .chain(positives)
+        .collect()
+}
+
+fn compute_1(grid: &Grid<char>) -> usize {
+    lines(grid)
+        .iter()
+        // Check each line for XMAS|SAMX
+        .map(|line| count_words(line))
+        // Count
+        .sum()
+}
+
+// Both diagonals of the 3x3 square centered on `center`, if it fits in the grid
+fn sub_x(grid: &Grid<char>, center: Point) -> Option<(String, String)> {
+    let corner = |dir: Dir| grid.step(center, dir).map(|point| grid[point]);
+    let c: char = grid[center];
+    // Negative slop (\)
+    let neg: String = [corner(Dir::UpLeft)?, c, corner(Dir::DownRight)?]
+        .iter()
+        .collect();
+    // Positive slop (/)
+    let pos: String = [corner(Dir::UpRight)?, c, corner(Dir::DownLeft)?]
+        .iter()
+        .collect();
+    Some((neg, pos))
+}
+
+fn compute_2(grid: &Grid<char>) -> usize {
+    let mas: Regex = Regex::new(r"MAS|SAM").unwrap();
+    grid.iter()
+        // Center of the X-MAS
+        .filter(|(_, c)| **c == 'A')
+        // Retrieve both diagonals
+        .filter_map(|(center, _)| sub_x(grid, center))
+        // If both are MAS|SAM, count this X-MAS
+        .filter(|(neg, pos)| mas.is_match(neg) && mas.is_match(pos))
+        .count()
+}
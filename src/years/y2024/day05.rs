@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::{Info, Solution};
+use crate::utils::parse::{self, int, list, pair};
+use crate::utils::preflight::Shape;
+
+/// Ordering rules: each page maps to the pages that must come after it
+pub struct Rules {
+    after: HashMap<i64, Vec<i64>>,
+}
+
+/// The pages of an update, in print order
+type Update = Vec<i64>;
+
+/// The whole manual: rules first, then updates
+pub struct Manual {
+    rules: Rules,
+    updates: Vec<Update>,
+}
+
+fn part1(manual: &Manual) -> Result<Answer> {
+    let mut count: i64 = 0;
+    for numbers in &manual.updates {
+        let mut copy: Vec<i64> = numbers.clone();
+        sort_updates(&manual.rules, &mut copy);
+        if vec_equals(numbers, &copy) {
+            count += numbers.get(numbers.len() / 2).unwrap();
+        }
+    }
+    Ok(count.into())
+}
+
+fn part2(manual: &Manual) -> Result<Answer> {
+    let mut count: i64 = 0;
+    for numbers in &manual.updates {
+        let mut copy: Vec<i64> = numbers.clone();
+        sort_updates(&manual.rules, &mut copy);
+        if !vec_equals(numbers, &copy) {
+            count += copy.get(numbers.len() / 2).unwrap();
+        }
+    }
+    Ok(count.into())
+}
+
+fn parse(input: &str) -> Result<Manual> {
+    let mut rules = Rules {
+        after: HashMap::<i64, Vec<i64>>::new(),
+    };
+    // Rules first, then updates, after a blank line
+    let [rule_lines, update_lines] = parse::sections(input)?;
+    for (left, right) in rule_lines.lines(pair("|", int(), int()))? {
+        // Greater than
+        rules.after.entry(left).or_default().push(right);
+    }
+    let updates: Vec<Update> = update_lines.lines(list(",", int()))?;
+    Ok(Manual { rules, updates })
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Manual;
+
+    fn info(&self) -> Info {
+        Info {
+            year: 2024,
+            day: 5,
+            title: "Print Queue",
+        }
+    }
+
+    fn shape(&self) -> Shape {
+        Shape::Sections(2)
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
+
+// Retrieve the `left|right` pair of the `i`-th line
+fn compare(rules: &Rules, a: &i64, b: &i64) -> std::cmp::Ordering {
+    match rules.after.get(a) {
+        Some(l) => {
+            if !l.contains(b) {
+                return std::cmp::Ordering::Greater;
+            }
+        }
+        _ => return std::cmp::Ordering::Greater,
+    }
+    std::cmp::Ordering::Less
+}
+
+fn sort_updates(rules: &Rules, numbers: &mut [i64]) {
+    numbers.sort_by(|a, b| compare(rules, a, b));
+}
+
+fn vec_equals(left: &[i64], right: &[i64]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+    for i in 0..left.len() {
+        let (a, b): (&i64, &i64) = (left.get(i).unwrap(), right.get(i).unwrap());
+        if a != b {
+            return false;
+        }
+    }
+    true
+}
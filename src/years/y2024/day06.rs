@@ -0,0 +1,188 @@
+use std::collections::HashSet;
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::solution::{Info, Solution};
+use crate::utils::{cancel, cycle};
+use crate::utils::grid::{Dir, Grid, Point};
+use crate::utils::preflight::Shape;
+use crate::utils::render::{Color, Frame, Frames};
+use crate::utils::runner::Executor;
+use crate::utils::search;
+
+/// The guard position and facing direction
+type Guard = (Point, Dir);
+
+/// The lab map and where the guard starts
+pub struct Lab {
+    grid: Grid<char>,
+    guard: Guard,
+}
+
+fn part1(lab: &Lab) -> Result<Answer> {
+    Ok(path(lab).len().into())
+}
+
+fn part2(lab: &Lab) -> Result<Answer> {
+    Ok(loop_stones(lab)?.len().into())
+}
+
+// Every stone on the guard's path that traps it in a loop
+fn loop_stones(lab: &Lab) -> Result<Vec<Point>> {
+    let mut stones: HashSet<Point> = path(lab);
+
+    // The start position can't be a valid stone position
+    stones.remove(&lab.guard.0);
+
+    // Try each stone on the guard's path
+    let stones: Vec<Point> = stones.into_iter().collect();
+    let loops = Executor::default().map(&stones, |&stone| {
+        is_loop(&set_stone(&lab.grid, stone), lab.guard)
+    })?;
+    let mut trapping: Vec<Point> = Vec::new();
+    for (stone, trapped) in stones.into_iter().zip(loops) {
+        if trapped? {
+            trapping.push(stone);
+        }
+    }
+    Ok(trapping)
+}
+
+// Whether the guard walks in a loop from `guard`, rather than leaving the lab
+fn is_loop(grid: &Grid<char>, guard: Guard) -> Result<bool> {
+    let mut steps: usize = 0;
+    let cycle = cycle::brent(guard, |guard| {
+        // The walk may be long, give up on it once out of time (checking now and then only)
+        steps += 1;
+        if steps.is_multiple_of(1024) && cancel::cancelled() {
+            return None;
+        }
+        step(grid, *guard)
+    });
+    // A walk given up on tells nothing
+    cancel::check()?;
+    Ok(cycle.is_some())
+}
+
+// The guard's next state: turning right in front of an obstacle, walking forward otherwise
+fn step(grid: &Grid<char>, (point, dir): Guard) -> Option<Guard> {
+    let next = grid.step(point, dir)?;
+    match grid[next] {
+        '#' => Some((point, dir.turn_right())),
+        _ => Some((next, dir)),
+    }
+}
+
+// Every position of the guard, until it leaves the lab
+fn path(lab: &Lab) -> HashSet<Point> {
+    search::reachable(lab.guard, |guard| step(&lab.grid, *guard))
+        .into_iter()
+        .map(|(point, _)| point)
+        .collect()
+}
+
+fn guard_char(dir: Dir) -> char {
+    match dir {
+        Dir::Up => '^',
+        Dir::Right => '>',
+        Dir::Down => 'v',
+        _ => '<',
+    }
+}
+
+// A frame per step of the guard, until it leaves the lab (or loops)
+fn walk_frames(lab: &Lab) -> Frames<'_> {
+    let start = lab.guard.0;
+    let mut guard: Option<Guard> = Some(lab.guard);
+    let mut visited: HashSet<Point> = HashSet::new();
+    let mut seen: HashSet<Guard> = HashSet::new();
+
+    Box::new(std::iter::from_fn(move || {
+        let (point, dir) = guard?;
+        visited.insert(point);
+        let frame = Frame::new(&lab.grid)
+            .mark(start, '.')
+            .mark(point, guard_char(dir))
+            .highlight([point], Color::Red)
+            .highlight(visited.iter().copied(), Color::Yellow)
+            .caption(format!("{} positions visited", visited.len()));
+
+        guard = match seen.insert((point, dir)) {
+            true => step(&lab.grid, (point, dir)),
+            false => None,
+        };
+        Some(frame)
+    }))
+}
+
+fn parse(input: &str) -> Result<Lab> {
+    let start_chars: Vec<char> = vec!['^', '>', 'v', '<'];
+    let grid: Grid<char> = Grid::parse(input)?;
+    // Find the start pos
+    let start: Point = grid.position(|c| start_chars.contains(c)).ok_or_else(|| {
+        // The whole map was read without finding the guard
+        Error::parse(grid.height() + 1, 1, "expected a guard (`^`, `>`, `v` or `<`)")
+    })?;
+    let dir = match grid[start] {
+        '^' => Dir::Up,
+        '>' => Dir::Right,
+        'v' => Dir::Down,
+        _ => Dir::Left,
+    };
+    let guard: Guard = (start, dir);
+    Ok(Lab { grid, guard })
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Lab;
+
+    fn info(&self) -> Info {
+        Info {
+            year: 2024,
+            day: 6,
+            title: "Guard Gallivant",
+        }
+    }
+
+    fn shape(&self) -> Shape {
+        Shape::Grid
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+
+    fn visualize<'a>(&self, input: &'a Self::Input, part: u8) -> Option<Frames<'a>> {
+        match part {
+            1 => Some(walk_frames(input)),
+            // The whole walk, with the stones trapping the guard
+            _ => {
+                let stones: Vec<Point> = loop_stones(input).ok()?;
+                let walk = walk_frames(input).last()?;
+                let frame = stones
+                    .iter()
+                    .fold(walk, |frame, stone| frame.mark(*stone, 'O'))
+                    .highlight(stones.iter().copied(), Color::Green)
+                    .caption(format!("{} stones trap the guard", stones.len()));
+                Some(Box::new(std::iter::once(frame)))
+            }
+        }
+    }
+}
+
+fn set_stone(matrix: &Grid<char>, pos: Point) -> Grid<char> {
+    let mut cloned: Grid<char> = matrix.clone();
+    // Modify pos as '#'
+    cloned[pos] = '#';
+    cloned
+}
@@ -0,0 +1,104 @@
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::{Info, Solution};
+use crate::utils::{cancel, checked};
+use crate::utils::parse::{self, int, map, record};
+use crate::utils::runner::Executor;
+
+/// A `total: numbers...` calibration equation, missing its operators
+pub struct Equation {
+    total: i64,
+    numbers: Vec<i64>,
+}
+
+/// A binary operator, failing when it overflows
+type Operation = fn(i64, i64) -> Result<i64>;
+
+fn part1(equations: &[Equation]) -> Result<Answer> {
+    Ok(calibration(equations, &[checked::add, checked::mul])?.into())
+}
+
+fn part2(equations: &[Equation]) -> Result<Answer> {
+    let operations: [Operation; 3] = [checked::add, checked::mul, checked::concat];
+    Ok(calibration(equations, &operations)?.into())
+}
+
+// Sum of the totals of the equations that `operations` can solve
+fn calibration(equations: &[Equation], operations: &[Operation]) -> Result<i64> {
+    Executor::default()
+        .map_reduce(
+            equations,
+            |equation| compute(equation.total, &equation.numbers, operations),
+            |a, b| checked::add(a?, b?),
+        )?
+        .unwrap_or(Ok(0))
+}
+
+// Retrieve every `total: numbers...` equation
+fn parse(input: &str) -> Result<Vec<Equation>> {
+    let equation = map(record(int(), int()), |(total, numbers)| Equation { total, numbers });
+    parse::lines(input, equation)
+}
+
+fn compute(total: i64, numbers: &[i64], operations: &[Operation]) -> Result<i64> {
+    // Each equation may take long, give up between them once out of time
+    cancel::check()?;
+    if combinaisons(numbers, operations, total)?.contains(&total) {
+        Ok(total)
+    } else {
+        Ok(0)
+    }
+}
+
+fn combinaisons(numbers: &[i64], operations: &[Operation], max_value: i64) -> Result<Vec<i64>> {
+    match numbers.len() {
+        0 => return Ok([].to_vec()),
+        1 => return Ok([*numbers.first().unwrap()].to_vec()),
+        _ => {}
+    }
+
+    let (first, second) = (*numbers.first().unwrap(), *numbers.get(1).unwrap());
+    // Create combinaisons for every operations
+    let computeds: Vec<Vec<i64>> = operations
+        .iter()
+        // Apply operations
+        .map(|f| f(first, second))
+        .collect::<Result<Vec<i64>>>()?
+        .into_iter()
+        // Remove numbers that exceed `max_value`` (no substraction operation provided)
+        .filter(|value| value <= &max_value)
+        .map(|value| [[value].to_vec(), numbers[2..].to_vec()].concat())
+        .collect();
+    // Compute childrens
+    Ok(computeds
+        .iter()
+        .map(|n| combinaisons(n, operations, max_value))
+        .collect::<Result<Vec<Vec<i64>>>>()?
+        .concat())
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+
+    fn info(&self) -> Info {
+        Info {
+            year: 2024,
+            day: 7,
+            title: "Bridge Repair",
+        }
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
@@ -1,55 +1,46 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::utils::matrix::{from_file, Matrix};
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::{Info, Solution};
+use crate::utils::grid::Grid;
+use crate::utils::preflight::Shape;
+
+/// The antennas of each frequency, within the city bounds
+pub struct City {
+    antennas: HashMap<char, Vec<(usize, usize)>>,
+    width: usize,
+    height: usize,
+}
 
-static INPUT_FILE_PATH: &str = "inputs/day08.txt";
+fn part1(city: &City) -> Result<Answer> {
+    let antinodes: HashSet<(usize, usize)> =
+        calculate_antinodes(&city.antennas, city.width, city.height);
+    Ok(antinodes.len().into())
+}
 
-fn part1(path: &str) -> i64 {
-    let mut antennas: HashMap<char, Vec<(usize, usize)>> =
-        HashMap::<char, Vec<(usize, usize)>>::new();
-    let matrix: Matrix = from_file(path).unwrap();
-    let (width, height) = (matrix.width().unwrap(), matrix.height());
-
-    for i in 0..height {
-        for j in 0..width {
-            match matrix.get(j, i).unwrap() {
-                '.' => (),
-                c => {
-                    antennas
-                        .entry(*c)
-                        .or_insert(Vec::<(usize, usize)>::new())
-                        .push((j, i));
-                }
-            }
-        }
-    }
-    let antinodes: HashSet<(usize, usize)> = calculate_antinodes(&antennas, width, height);
-    return antinodes.len().try_into().unwrap();
+fn part2(city: &City) -> Result<Answer> {
+    let antinodes: HashSet<(usize, usize)> =
+        calculate_antinodes_fixed_point(&city.antennas, city.width, city.height);
+
+    Ok(antinodes.len().into())
 }
 
-fn part2(path: &str) -> i64 {
+fn parse(input: &str) -> Result<City> {
+    let grid: Grid<char> = Grid::parse(input)?;
     let mut antennas: HashMap<char, Vec<(usize, usize)>> =
         HashMap::<char, Vec<(usize, usize)>>::new();
-    let matrix: Matrix = from_file(path).unwrap();
-    let (width, height) = (matrix.width().unwrap(), matrix.height());
-
-    for i in 0..height {
-        for j in 0..width {
-            match matrix.get(j, i).unwrap() {
-                '.' => (),
-                c => {
-                    antennas
-                        .entry(*c)
-                        .or_insert(Vec::<(usize, usize)>::new())
-                        .push((j, i));
-                }
-            }
+
+    for (point, c) in grid.iter() {
+        if *c != '.' {
+            antennas.entry(*c).or_default().push((point.x, point.y));
         }
     }
-    let antinodes: HashSet<(usize, usize)> =
-        calculate_antinodes_fixed_point(&antennas, width, height);
-
-    return antinodes.len().try_into().unwrap();
+    Ok(City {
+        antennas,
+        width: grid.width(),
+        height: grid.height(),
+    })
 }
 
 fn calculate_symetries(
@@ -72,11 +63,11 @@ fn calculate_symetries(
         symetries.insert((2 * x_b - x_a, 2 * y_b - y_a));
     }
 
-    return symetries;
+    symetries
 }
 
 fn calculate_antinode(
-    antennas: &Vec<(usize, usize)>,
+    antennas: &[(usize, usize)],
     width: usize,
     height: usize,
 ) -> HashSet<(usize, usize)> {
@@ -89,7 +80,7 @@ fn calculate_antinode(
             antinodes.extend(&calculate_symetries(head, elt, width, height));
         }
     }
-    return antinodes;
+    antinodes
 }
 
 fn calculate_antinodes(
@@ -99,13 +90,13 @@ fn calculate_antinodes(
 ) -> HashSet<(usize, usize)> {
     let mut antinodes: HashSet<(usize, usize)> = HashSet::<(usize, usize)>::new();
     for (_, v) in antennas.iter() {
-        antinodes.extend(&calculate_antinode(&v, width, height));
+        antinodes.extend(&calculate_antinode(v, width, height));
     }
-    return antinodes;
+    antinodes
 }
 
 fn calculate_antinode_fixed_point(
-    antennas: &Vec<(usize, usize)>,
+    antennas: &[(usize, usize)],
     width: usize,
     height: usize,
 ) -> HashSet<(usize, usize)> {
@@ -118,7 +109,7 @@ fn calculate_antinode_fixed_point(
 
             // Create the starting antinodes
             let mut computed: HashSet<(usize, usize)> =
-                calculate_antinode(&[*head, *elt].to_vec(), width, height);
+                calculate_antinode(&[*head, *elt], width, height);
             let mut size = computed.len();
 
             // Iterate until fixed point
@@ -143,7 +134,7 @@ fn calculate_antinode_fixed_point(
             antinodes.insert(*elt);
         }
     }
-    return antinodes;
+    antinodes
 }
 
 fn calculate_antinodes_fixed_point(
@@ -153,36 +144,37 @@ fn calculate_antinodes_fixed_point(
 ) -> HashSet<(usize, usize)> {
     let mut antinodes: HashSet<(usize, usize)> = HashSet::<(usize, usize)>::new();
     for (_, v) in antennas.iter() {
-        antinodes.extend(&calculate_antinode_fixed_point(&v, width, height));
+        antinodes.extend(&calculate_antinode_fixed_point(v, width, height));
     }
-    return antinodes;
+    antinodes
 }
 
-pub fn solve(part: i64) -> i64 {
-    match part {
-        1 => part1(INPUT_FILE_PATH),
-        2 => part2(INPUT_FILE_PATH),
-        _ => unimplemented!(),
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = City;
+
+    fn info(&self) -> Info {
+        Info {
+            year: 2024,
+            day: 8,
+            title: "Resonant Collinearity",
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn shape(&self) -> Shape {
+        Shape::Grid
+    }
 
-    static EXAMPLE_FILE_PATH: &str = "inputs/day08.example.txt";
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
 
-    #[test]
-    fn example_1() {
-        let result = part1(EXAMPLE_FILE_PATH);
-        let expected: i64 = 14;
-        assert_eq!(result, expected);
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
     }
 
-    #[test]
-    fn example_2() {
-        let result = part2(EXAMPLE_FILE_PATH);
-        let expected: i64 = 34;
-        assert_eq!(result, expected);
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
     }
 }
@@ -0,0 +1,218 @@
+use std::fmt;
+
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::{Info, Solution};
+use crate::utils::checked;
+use crate::utils::parse::{digits, Section};
+use crate::utils::preflight::Shape;
+
+#[derive(Clone, Copy)]
+enum Block {
+    File(usize, usize),
+    Space(usize),
+}
+
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Block::File(size, index) => write!(f, "{}", vec![index.to_string(); *size].concat()),
+            Block::Space(size) => write!(f, "{}", vec!["."; *size].concat()),
+        }
+    }
+}
+
+/// The disk map: alternating file and free space sizes, one digit each
+type DiskMap = Vec<usize>;
+
+fn part1(sizes: &DiskMap) -> Result<Answer> {
+    Ok(checksum(&compact(&expand(sizes)))?.into())
+}
+
+fn part2(sizes: &DiskMap) -> Result<Answer> {
+    Ok(block_checksum(&block_compact(&block_expand(sizes)))?.into())
+}
+
+// Retrieve the disk map from the first line, made of digits only
+fn parse(input: &str) -> Result<DiskMap> {
+    let sizes = Section::new(input).line(digits())?;
+    Ok(sizes.into_iter().map(usize::from).collect())
+}
+
+fn expand(sizes: &[usize]) -> Vec<Option<i64>> {
+    let mut space: bool = false;
+    let mut index: usize = 0;
+    // Iterate through sizes
+    sizes
+        .iter()
+        // Expand each size
+        // Writing its index or '.' if space
+        .flat_map(|&size| {
+            let expanded = match space {
+                true => vec![None; size],
+                false => {
+                    let value = vec![Some(index as i64); size];
+                    index += 1;
+                    value
+                }
+            };
+            space = !space;
+            expanded
+        })
+        .collect()
+}
+
+fn compact(expanded: &[Option<i64>]) -> Vec<Option<i64>> {
+    // Size is only the number of available space
+    let size = expanded.len();
+    let mut compacted = expanded.to_vec();
+
+    for i in (0..size).rev() {
+        match compacted.get(i) {
+            None => continue,
+            Some(_) => {
+                // Find first occurence of None
+                let first_occ: usize = compacted.iter().position(|elt| elt.is_none()).unwrap();
+                // Finished compacting
+                if first_occ > i {
+                    break;
+                }
+                // Swap value at index `first_occ` with value at index `i`
+                compacted.swap(first_occ, i);
+            }
+        }
+    }
+
+    compacted
+}
+
+fn checksum(compacted: &[Option<i64>]) -> Result<i64> {
+    let products = compacted
+        .iter()
+        // Filter out dots
+        .filter(|c| c.is_some())
+        .enumerate()
+        // Convert char to u32 and multiply them by their index
+        .map(|(i, c)| checked::mul(c.unwrap(), i as i64))
+        .collect::<Result<Vec<i64>>>()?;
+    // Add everything
+    checked::sum(products)
+}
+
+fn block_expand(sizes: &[usize]) -> Vec<Block> {
+    let mut space: bool = false;
+    let mut index: usize = 0;
+    // Iterate through sizes
+    sizes
+        .iter()
+        // Expand each size
+        // Writing its index or '.' if space
+        .map(|&size| {
+            let expanded = match space {
+                true => Block::Space(size),
+                false => {
+                    let value = Block::File(size, index);
+                    index += 1;
+                    value
+                }
+            };
+            space = !space;
+            expanded
+        })
+        .collect()
+}
+
+fn block_compact(expanded: &[Block]) -> Vec<Block> {
+    // Size is only the number of available space
+    let size = expanded.len();
+    let mut compacted = expanded.to_vec();
+
+    for i in (0..size).rev() {
+        match compacted.get(i).cloned() {
+            // Find the rightest most block
+            Some(Block::File(file_size, _)) => {
+                // Iterate through empty spaces and try to insert our file into available space
+                for j in 0..i {
+                    if let Some(Block::Space(empty_size)) = compacted.get(j).cloned() {
+                        // It can fit !
+                        if empty_size >= file_size {
+                            // Swap both blocks
+                            compacted.swap(i, j);
+                            // If empty_size is larger than file_size
+                            // ex: Space(20), ..., File(12) => File(12) + Space(8), ..., Space(12)
+                            if empty_size > file_size {
+                                // * We have to remove some Space space to swapped Space space
+                                compacted.drain(i..i + 1);
+                                compacted.insert(i, Block::Space(file_size));
+                                // * We have to insert back some Space space
+                                let delta = empty_size - file_size;
+                                compacted.insert(j + 1, Block::Space(delta));
+                            }
+                            // Stop looking for empty space
+                            break;
+                        }
+                    }
+                }
+            }
+            _ => continue,
+        }
+    }
+    compacted
+}
+
+fn block_checksum(compacted: &[Block]) -> Result<usize> {
+    let mut index: usize = 0;
+    let values = compacted
+        .iter()
+        .map(|block| match block {
+            Block::File(size, file_index) => {
+                // Iterate on block to calculate its full size
+                let value = checked::sum(
+                    (index..index + size)
+                        .map(|n| checked::mul(*file_index, n))
+                        .collect::<Result<Vec<usize>>>()?,
+                );
+                // Increment by file size
+                index += size;
+                value
+            }
+            Block::Space(size) => {
+                // Increment by space size
+                index += size;
+                // Empty so worth zero
+                Ok(0)
+            }
+        })
+        .collect::<Result<Vec<usize>>>()?;
+    checked::sum(values)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = DiskMap;
+
+    fn info(&self) -> Info {
+        Info {
+            year: 2024,
+            day: 9,
+            title: "Disk Fragmenter",
+        }
+    }
+
+    fn shape(&self) -> Shape {
+        Shape::Line
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}
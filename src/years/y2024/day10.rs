@@ -0,0 +1,133 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::{Info, Solution};
+use crate::utils::grid::{Grid, Point};
+use crate::utils::parse::{digit, Section};
+use crate::utils::preflight::Shape;
+use crate::utils::render::{Color, Frame, Frames};
+use crate::utils::search;
+
+fn part1(heights: &Grid<u8>) -> Result<Answer> {
+    // Count the peaks reachable from each trailhead
+    let score = |trailhead: Point| {
+        search::reachable(trailhead, |point| uphill(heights, *point))
+            .into_iter()
+            .filter(|point| heights[*point] == 9)
+            .count()
+    };
+    Ok(trailheads(heights).map(score).sum::<usize>().into())
+}
+
+fn part2(heights: &Grid<u8>) -> Result<Answer> {
+    // Count the distinct trails from each trailhead to a peak
+    let rating = |trailhead: Point| {
+        search::count_paths(
+            trailhead,
+            |point| uphill(heights, *point),
+            |point| heights[*point] == 9,
+        )
+    };
+    Ok(trailheads(heights).map(rating).sum::<usize>().into())
+}
+
+fn parse(input: &str) -> Result<Grid<u8>> {
+    // Every cell must be a height
+    Section::new(input).grid(digit)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u8>;
+
+    fn info(&self) -> Info {
+        Info {
+            year: 2024,
+            day: 10,
+            title: "Hoof It",
+        }
+    }
+
+    fn shape(&self) -> Shape {
+        Shape::Grid
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+
+    fn visualize<'a>(&self, input: &'a Self::Input, part: u8) -> Option<Frames<'a>> {
+        Some(search_frames(input, part))
+    }
+}
+
+fn trailheads(heights: &Grid<u8>) -> impl Iterator<Item = Point> + '_ {
+    heights
+        .iter()
+        .filter(|(_, height)| **height == 0)
+        .map(|(point, _)| point)
+}
+
+// The cells around `point` one step higher
+fn uphill(heights: &Grid<u8>, point: Point) -> impl Iterator<Item = Point> + '_ {
+    let height = heights[point];
+    heights
+        .neighbours4(point)
+        .filter(move |next| heights[*next] == height + 1)
+}
+
+// A frame per height climbed from each trailhead, counting the cells reached at the current height
+// for `part` 1 (so the summits, in the end), or the trails reaching them for `part` 2
+fn search_frames(heights: &Grid<u8>, part: u8) -> Frames<'_> {
+    let trailheads: Vec<Point> = trailheads(heights).collect();
+    let count = trailheads.len();
+
+    Box::new(
+        trailheads
+            .into_iter()
+            .enumerate()
+            .flat_map(move |(i, trailhead)| {
+                // Number of trails reaching each cell of the current height
+                let mut trails: HashMap<Point, usize> = HashMap::from([(trailhead, 1)]);
+                let mut climbed: HashSet<Point> = HashSet::new();
+                (0..=9).map(move |height| {
+                    if height > 0 {
+                        let mut next: HashMap<Point, usize> = HashMap::new();
+                        for (&point, &n) in &trails {
+                            for neighbour in heights.neighbours4(point) {
+                                if heights[neighbour] == height {
+                                    *next.entry(neighbour).or_default() += n;
+                                }
+                            }
+                        }
+                        trails = next;
+                    }
+                    climbed.extend(trails.keys());
+                    let color = if height == 9 { Color::Green } else { Color::Red };
+                    let reached = match part {
+                        1 => format!("{} cells of height {} reached", trails.len(), height),
+                        _ => format!(
+                            "{} cells of height {}, reached by {} trails",
+                            trails.len(),
+                            height,
+                            trails.values().sum::<usize>()
+                        ),
+                    };
+                    Frame::new(heights)
+                        .highlight(trails.keys().copied(), color)
+                        .highlight(climbed.iter().copied(), Color::Blue)
+                        .caption(format!("trailhead {}/{}: {}", i + 1, count, reached))
+                })
+            }),
+    )
+}
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::{Info, Solution};
+use crate::utils::{cancel, checked};
+use crate::utils::parse::{self, int, words};
+use crate::utils::preflight::Shape;
+
+/// How many stones are engraved with each number
+type Stones = HashMap<String, u64>;
+
+fn part1(stones: &Stones) -> Result<Answer> {
+    let mut stones_map: Stones = stones.clone();
+    // Iterate 25 times
+    for _ in 0..25 {
+        stones_map = blink_map(&stones_map)?;
+    }
+    // Compute the result
+    Ok(checked::sum(stones_map.values().copied())?.into())
+}
+
+fn part2(stones: &Stones) -> Result<Answer> {
+    let mut stones_map: Stones = stones.clone();
+    // Iterate 75 times
+    for _ in 0..75 {
+        stones_map = blink_map(&stones_map)?;
+    }
+    // Compute the result
+    Ok(checked::sum(stones_map.values().copied())?.into())
+}
+
+fn parse(input: &str) -> Result<Stones> {
+    let mut stones_map: Stones = HashMap::<String, u64>::new();
+    // Insert default values for stones
+    for stone in parse::lines(input, words(int::<u64>()))?.concat() {
+        *stones_map.entry(stone.to_string()).or_default() += 1;
+    }
+    Ok(stones_map)
+}
+
+fn blink(stone: &str) -> Result<Vec<String>> {
+    // Every engraved number was parsed or computed as a `u64`
+    let number: u64 = stone.parse::<u64>().unwrap();
+    if number == 0 {
+        // If the stone is engraved with the number 0, it is replaced by a stone engraved with the number 1
+
+        Ok(vec![String::from("1")])
+    } else if stone.len().is_multiple_of(2) {
+        // If the stone is engraved with a number that has an even number of digits, it is replaced by two stones. The left half of the digits are engraved on the new left stone, and the right half of the digits are engraved on the new right stone. (The new numbers don't keep extra leading zeroes: 1000 would become stones 10 and 0.)
+
+        let length: usize = stone.len();
+        let (left, right) = (
+            stone[0..length / 2].to_string(),
+            // Parsed to `i64` then back to `String` to remove zeros
+            stone[length / 2..]
+                .to_string()
+                .parse::<u64>()
+                .unwrap()
+                .to_string(),
+        );
+        // Remove index `i`
+        // Insert `left` & `right`
+        Ok(vec![left, right])
+    } else {
+        // If none of the other rules apply, the stone is replaced by a new stone; the old stone's number multiplied by 2024 is engraved on the new stone
+
+        Ok(vec![checked::mul(number, 2024)?.to_string()])
+    }
+}
+
+fn blink_map(stones: &HashMap<String, u64>) -> Result<HashMap<String, u64>> {
+    cancel::check()?;
+    let mut output: HashMap<String, u64> = HashMap::<String, u64>::new();
+    for (n, v) in stones.iter() {
+        let blinkeds = blink(n)?;
+        for stone in blinkeds {
+            let count = output.entry(stone).or_default();
+            *count = checked::add(*count, *v)?;
+        }
+    }
+    Ok(output)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Stones;
+
+    fn info(&self) -> Info {
+        Info {
+            year: 2024,
+            day: 11,
+            title: "Plutonian Pebbles",
+        }
+    }
+
+    fn shape(&self) -> Shape {
+        Shape::Line
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        part2(input)
+    }
+}